@@ -0,0 +1,22 @@
+/// Mirrors the `gpg_error_1_*` and `gpgrt_lock` cfgs set by
+/// `libgpg-error-sys` using the values it reports through `DEP_GPG_ERROR_*`.
+fn main() {
+    build::rerun_if_changed("build.rs");
+    let minor = |key| {
+        build::dep("gpg-error", key)
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or_else(|| panic!("libgpg-error-sys did not report `{key}`"))
+    };
+    let (min, max) = (minor("min_minor"), minor("max_minor"));
+    for minor in min..=max {
+        println!("cargo:rustc-check-cfg=cfg(gpg_error_1_{minor})");
+    }
+    for minor in min..=minor("minor") {
+        build::rustc_cfg(&format!("gpg_error_1_{minor}"));
+    }
+
+    println!("cargo:rustc-check-cfg=cfg(gpgrt_lock)");
+    if build::dep("gpg-error", "lock_size").is_some() {
+        build::rustc_cfg("gpgrt_lock");
+    }
+}
@@ -1,16 +1,210 @@
-use std::error::Error;
+use std::{env, error::Error, path::PathBuf};
+
+/// The oldest supported release; `gpg_error_1_*` cfgs start here.
+const MIN_MINOR: u32 = 12;
+/// The newest release for which `gpg_error_1_*` cfgs are declared.
+const MAX_MINOR: u32 = 55;
+/// The release the `pure_rust` fallback tables were taken from.
+#[cfg(feature = "pure_rust")]
+const FALLBACK_VERSION: &str = "1.48";
 
 fn main() -> Result<(), Box<dyn Error>> {
     build::rerun_if_changed("build.rs");
+    for minor in MIN_MINOR..=MAX_MINOR {
+        println!("cargo:rustc-check-cfg=cfg(gpg_error_1_{minor})");
+    }
+    // Passed on to dependents as `DEP_GPG_ERROR_*`, so they can mirror the
+    // version cfgs without duplicating this logic.
+    println!("cargo:min_minor={MIN_MINOR}");
+    println!("cargo:max_minor={MAX_MINOR}");
+    emit_lock_cfgs();
+
+    if build::cargo_feature("pure_rust")
+        && (build::cargo_feature("vendored") || build::cargo_feature("bindgen"))
+    {
+        return Err(
+            "the `pure_rust` feature cannot be combined with `vendored` or `bindgen`".into(),
+        );
+    }
+    if try_fallback() || try_vendored()? {
+        return Ok(());
+    }
 
     if build::cargo_cfg_windows() && (build::cargo_feature("windows_raw_dylib") || try_registry()) {
+        emit_version_cfgs(None);
+        generate_bindings(&[])?;
         return Ok(());
     }
 
-    system_deps::Config::new().probe()?;
+    let libs = system_deps::Config::new().probe()?;
+    let lib = libs
+        .get_by_name("gpg-error")
+        .ok_or("gpg-error was not probed")?;
+    println!("cargo:version={}", lib.version);
+    if !lib.include_paths.is_empty() {
+        let paths = env::join_paths(&lib.include_paths)?;
+        println!("cargo:include={}", paths.to_string_lossy());
+    }
+    println!("cargo:link={}", if lib.statik { "static" } else { "dylib" });
+    emit_version_cfgs(Some(&lib.version));
+    generate_bindings(&lib.include_paths)?;
+    Ok(())
+}
+
+/// Parses the minor release number of a `1.x` version, saturating newer
+/// major versions to [`MAX_MINOR`].
+fn parse_minor(version: &str) -> Option<u32> {
+    let mut parts = version.split('.').map(|p| {
+        p.bytes().take_while(u8::is_ascii_digit).fold(0u32, |n, d| {
+            n.saturating_mul(10).saturating_add(u32::from(d - b'0'))
+        })
+    });
+    match (parts.next()?, parts.next()?) {
+        (0, _) => None,
+        (1, minor) => Some(minor),
+        _ => Some(MAX_MINOR),
+    }
+}
+
+/// The size of the platform mutex wrapped by `gpgrt_lock_t`, as recorded in
+/// libgpg-error's lock-obj-pub.*.h files, if it is known for the target.
+fn lock_priv_size() -> Option<usize> {
+    let os = build::cargo_cfg_target_os();
+    let arch = build::cargo_cfg_target_arch();
+    let width = build::cargo_cfg_pointer_width();
+    match (os.as_str(), arch.as_str(), width) {
+        ("linux" | "android", "x86_64", 64) => Some(40),
+        ("android", "aarch64", _) => Some(40),
+        ("linux", "aarch64", _) => Some(48),
+        ("linux", "x86_64", _) => None,
+        ("linux", _, 32) => Some(24),
+        ("windows", _, 64) => Some(40),
+        ("windows", _, 32) => Some(24),
+        _ if build::cargo_cfg_target_vendor() == "apple" => Some(64),
+        _ => None,
+    }
+}
+
+/// Sets `gpgrt_lock` and `gpgrt_lock_size` if the layout of `gpgrt_lock_t`
+/// is known for the target. Otherwise the lock bindings are omitted.
+fn emit_lock_cfgs() {
+    println!("cargo:rustc-check-cfg=cfg(gpgrt_lock)");
+    println!("cargo:rustc-check-cfg=cfg(gpgrt_lock_size, values(\"24\", \"40\", \"48\", \"64\"))");
+    if let Some(size) = lock_priv_size() {
+        build::rustc_cfg("gpgrt_lock");
+        println!("cargo:rustc-cfg=gpgrt_lock_size=\"{size}\"");
+        println!("cargo:lock_size={size}");
+    }
+}
+
+/// Sets `gpg_error_1_N` for every release up to the detected `version`, or
+/// up to the release required by the enabled `v1_*` features if that is
+/// newer or the version is unknown.
+fn emit_version_cfgs(version: Option<&str>) {
+    let required = if build::cargo_feature("v1_49") {
+        49
+    } else if build::cargo_feature("v1_48") {
+        48
+    } else {
+        MIN_MINOR
+    };
+    let detected = version.and_then(parse_minor).unwrap_or(0);
+    let minor = detected.max(required).min(MAX_MINOR);
+    println!("cargo:minor={minor}");
+    for minor in MIN_MINOR..=minor {
+        build::rustc_cfg(&format!("gpg_error_1_{minor}"));
+    }
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_include_paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    Ok(())
+}
+
+/// Generates declarations for the whole gpgrt API from the installed
+/// `gpg-error.h`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    bindgen::Builder::default()
+        .header_contents("wrapper.h", "#include <gpg-error.h>\n")
+        .clang_args(include_paths.iter().map(|p| format!("-I{}", p.display())))
+        .allowlist_function("_?gpg(rt)?_.*")
+        .allowlist_type("_?gpg(rt)?_.*")
+        .allowlist_var("(GPG|GPGRT|ARGPARSE)_.*")
+        .prepend_enum_name(false)
+        .use_core()
+        .layout_tests(false)
+        .generate()?
+        .write_to_file(build::out_dir().join("bindings.rs"))?;
     Ok(())
 }
 
+#[cfg(not(feature = "pure_rust"))]
+fn try_fallback() -> bool {
+    false
+}
+
+#[cfg(feature = "pure_rust")]
+fn try_fallback() -> bool {
+    println!("cargo:version={FALLBACK_VERSION}");
+    emit_version_cfgs(Some(FALLBACK_VERSION));
+    true
+}
+
+#[cfg(not(feature = "vendored"))]
+fn try_vendored() -> Result<bool, Box<dyn Error>> {
+    Ok(false)
+}
+
+#[cfg(feature = "vendored")]
+fn try_vendored() -> Result<bool, Box<dyn Error>> {
+    use std::fs;
+
+    build::rerun_if_env_changed("GPG_ERROR_SRC_DIR");
+    let src = env::var_os("GPG_ERROR_SRC_DIR").map_or_else(
+        || build::cargo_manifest_dir().join("libgpg-error"),
+        PathBuf::from,
+    );
+    if !src.join("configure").is_file() {
+        return Err(format!(
+            "libgpg-error sources not found in {}; extract a release tarball there \
+             (see `make fetch-src`) or set GPG_ERROR_SRC_DIR",
+            src.display()
+        )
+        .into());
+    }
+    build::rerun_if_changed(src.join("configure"));
+    build::rerun_if_changed(src.join("VERSION"));
+
+    // Same options as docker/Dockerfile.static
+    let dst = autotools::Config::new(&src)
+        .with("pic", None)
+        .enable("fast-install", None)
+        .disable("dependency-tracking", None)
+        .enable_static()
+        .disable_shared()
+        .disable("nls", None)
+        .disable("doc", None)
+        .disable("languages", None)
+        .disable("tests", None)
+        .try_build()?;
+    build::rustc_link_search(dst.join("lib"));
+    build::rustc_link_lib("static=gpg-error");
+
+    let pc = fs::read_to_string(dst.join("lib/pkgconfig/gpg-error.pc"))?;
+    let version = pc
+        .lines()
+        .find_map(|l| l.strip_prefix("Version:"))
+        .map(str::trim)
+        .ok_or("missing version in gpg-error.pc")?;
+    println!("cargo:version={version}");
+    println!("cargo:include={}", dst.join("include").display());
+    println!("cargo:link=static");
+    emit_version_cfgs(Some(version));
+    generate_bindings(&[dst.join("include")])?;
+    Ok(true)
+}
+
 #[cfg(not(windows))]
 fn try_registry() -> bool {
     false
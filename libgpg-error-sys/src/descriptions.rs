@@ -0,0 +1,471 @@
+static SOURCE_DESCRIPTIONS: &[(gpg_err_source_t, &CStr)] = &[
+    (0, c"Unspecified source"),
+    (1, c"gcrypt"),
+    (2, c"GnuPG"),
+    (3, c"GpgSM"),
+    (4, c"GPG Agent"),
+    (5, c"Pinentry"),
+    (6, c"SCD"),
+    (7, c"GPGME"),
+    (8, c"Keybox"),
+    (9, c"KSBA"),
+    (10, c"Dirmngr"),
+    (11, c"GSTI"),
+    (12, c"GPA"),
+    (13, c"Kleopatra"),
+    (14, c"G13"),
+    (15, c"Assuan"),
+    (16, c"TPM2d"),
+    (17, c"TLS"),
+    (18, c"TKD"),
+    (31, c"Any source"),
+    (32, c"User defined source 1"),
+    (33, c"User defined source 2"),
+    (34, c"User defined source 3"),
+    (35, c"User defined source 4"),
+];
+static UNKNOWN_SOURCE: &CStr = c"Unknown source";
+static CODE_DESCRIPTIONS: &[(gpg_err_code_t, &CStr)] = &[
+    (0, c"Success"),
+    (1, c"General error"),
+    (2, c"Unknown packet"),
+    (3, c"Unknown version in packet"),
+    (4, c"Invalid public key algorithm"),
+    (5, c"Invalid digest algorithm"),
+    (6, c"Bad public key"),
+    (7, c"Bad secret key"),
+    (8, c"Bad signature"),
+    (9, c"No public key"),
+    (10, c"Checksum error"),
+    (11, c"Bad passphrase"),
+    (12, c"Invalid cipher algorithm"),
+    (13, c"Cannot open keyring"),
+    (14, c"Invalid packet"),
+    (15, c"Invalid armor"),
+    (16, c"No user ID"),
+    (17, c"No secret key"),
+    (18, c"Wrong secret key used"),
+    (19, c"Bad session key"),
+    (20, c"Unknown compression algorithm"),
+    (21, c"Number is not prime"),
+    (22, c"Invalid encoding method"),
+    (23, c"Invalid encryption scheme"),
+    (24, c"Invalid signature scheme"),
+    (25, c"Invalid attribute"),
+    (26, c"No value"),
+    (27, c"Not found"),
+    (28, c"Value not found"),
+    (29, c"Syntax error"),
+    (30, c"Bad MPI value"),
+    (31, c"Invalid passphrase"),
+    (32, c"Invalid signature class"),
+    (33, c"Resources exhausted"),
+    (34, c"Invalid keyring"),
+    (35, c"Trust DB error"),
+    (36, c"Bad certificate"),
+    (37, c"Invalid user ID"),
+    (38, c"Unexpected error"),
+    (39, c"Time conflict"),
+    (40, c"Keyserver error"),
+    (41, c"Wrong public key algorithm"),
+    (42, c"Tribute to D. A."),
+    (43, c"Weak encryption key"),
+    (44, c"Invalid key length"),
+    (45, c"Invalid argument"),
+    (46, c"Syntax error in URI"),
+    (47, c"Invalid URI"),
+    (48, c"Network error"),
+    (49, c"Unknown host"),
+    (50, c"Selftest failed"),
+    (51, c"Data not encrypted"),
+    (52, c"Data not processed"),
+    (53, c"Unusable public key"),
+    (54, c"Unusable secret key"),
+    (55, c"Invalid value"),
+    (56, c"Bad certificate chain"),
+    (57, c"Missing certificate"),
+    (58, c"No data"),
+    (59, c"Bug"),
+    (60, c"Not supported"),
+    (61, c"Invalid operation code"),
+    (62, c"Timeout"),
+    (63, c"Internal error"),
+    (64, c"EOF (gcrypt)"),
+    (65, c"Invalid object"),
+    (66, c"Provided object is too short"),
+    (67, c"Provided object is too large"),
+    (68, c"Missing item in object"),
+    (69, c"Not implemented"),
+    (70, c"Conflicting use"),
+    (71, c"Invalid cipher mode"),
+    (72, c"Invalid flag"),
+    (73, c"Invalid handle"),
+    (74, c"Result truncated"),
+    (75, c"Incomplete line"),
+    (76, c"Invalid response"),
+    (77, c"No agent running"),
+    (78, c"Agent error"),
+    (79, c"Invalid data"),
+    (80, c"Unspecific Assuan server fault"),
+    (81, c"General Assuan error"),
+    (82, c"Invalid session key"),
+    (83, c"Invalid S-expression"),
+    (84, c"Unsupported algorithm"),
+    (85, c"No pinentry"),
+    (86, c"pinentry error"),
+    (87, c"Bad PIN"),
+    (88, c"Invalid name"),
+    (89, c"Bad data"),
+    (90, c"Invalid parameter"),
+    (91, c"Wrong card"),
+    (92, c"No dirmngr"),
+    (93, c"dirmngr error"),
+    (94, c"Certificate revoked"),
+    (95, c"No CRL known"),
+    (96, c"CRL too old"),
+    (97, c"Line too long"),
+    (98, c"Not trusted"),
+    (99, c"Operation cancelled"),
+    (100, c"Bad CA certificate"),
+    (101, c"Certificate expired"),
+    (102, c"Certificate too young"),
+    (103, c"Unsupported certificate"),
+    (104, c"Unknown S-expression"),
+    (105, c"Unsupported protection"),
+    (106, c"Corrupted protection"),
+    (107, c"Ambiguous name"),
+    (108, c"Card error"),
+    (109, c"Card reset required"),
+    (110, c"Card removed"),
+    (111, c"Invalid card"),
+    (112, c"Card not present"),
+    (113, c"No PKCS15 application"),
+    (114, c"Not confirmed"),
+    (115, c"Configuration error"),
+    (116, c"No policy match"),
+    (117, c"Invalid index"),
+    (118, c"Invalid ID"),
+    (119, c"No SmartCard daemon"),
+    (120, c"SmartCard daemon error"),
+    (121, c"Unsupported protocol"),
+    (122, c"Bad PIN method"),
+    (123, c"Card not initialized"),
+    (124, c"Unsupported operation"),
+    (125, c"Wrong key usage"),
+    (126, c"Nothing found"),
+    (127, c"Wrong blob type"),
+    (128, c"Missing value"),
+    (129, c"Hardware problem"),
+    (130, c"PIN blocked"),
+    (131, c"Conditions of use not satisfied"),
+    (132, c"PINs are not synced"),
+    (133, c"Invalid CRL"),
+    (134, c"BER error"),
+    (135, c"Invalid BER"),
+    (136, c"Element not found"),
+    (137, c"Identifier not found"),
+    (138, c"Invalid tag"),
+    (139, c"Invalid length"),
+    (140, c"Invalid key info"),
+    (141, c"Unexpected tag"),
+    (142, c"Not DER encoded"),
+    (143, c"No CMS object"),
+    (144, c"Invalid CMS object"),
+    (145, c"Unknown CMS object"),
+    (146, c"Unsupported CMS object"),
+    (147, c"Unsupported encoding"),
+    (148, c"Unsupported CMS version"),
+    (149, c"Unknown algorithm"),
+    (150, c"Invalid crypto engine"),
+    (151, c"Public key not trusted"),
+    (152, c"Decryption failed"),
+    (153, c"Key expired"),
+    (154, c"Signature expired"),
+    (155, c"Encoding problem"),
+    (156, c"Invalid state"),
+    (157, c"Duplicated value"),
+    (158, c"Missing action"),
+    (159, c"ASN.1 module not found"),
+    (160, c"Invalid OID string"),
+    (161, c"Invalid time"),
+    (162, c"Invalid CRL object"),
+    (163, c"Unsupported CRL version"),
+    (164, c"Invalid certificate object"),
+    (165, c"Unknown name"),
+    (166, c"A locale function failed"),
+    (167, c"Not locked"),
+    (168, c"Protocol violation"),
+    (169, c"Invalid MAC"),
+    (170, c"Invalid request"),
+    (171, c"Unknown extension"),
+    (172, c"Unknown critical extension"),
+    (173, c"Locked"),
+    (174, c"Unknown option"),
+    (175, c"Unknown command"),
+    (176, c"Not operational"),
+    (177, c"No passphrase given"),
+    (178, c"No PIN given"),
+    (179, c"Not enabled"),
+    (180, c"No crypto engine"),
+    (181, c"Missing key"),
+    (182, c"Too many objects"),
+    (183, c"Limit reached"),
+    (184, c"Not initialized"),
+    (185, c"Missing issuer certificate"),
+    (186, c"No keyserver available"),
+    (187, c"Invalid elliptic curve"),
+    (188, c"Unknown elliptic curve"),
+    (189, c"Duplicated key"),
+    (190, c"Ambiguous result"),
+    (191, c"No crypto context"),
+    (192, c"Wrong crypto context"),
+    (193, c"Bad crypto context"),
+    (194, c"Conflict in the crypto context"),
+    (195, c"Broken public key"),
+    (196, c"Broken secret key"),
+    (197, c"Invalid MAC algorithm"),
+    (198, c"Operation fully cancelled"),
+    (199, c"Operation not yet finished"),
+    (200, c"Buffer too short"),
+    (201, c"Invalid length specifier in S-expression"),
+    (202, c"String too long in S-expression"),
+    (203, c"Unmatched parentheses in S-expression"),
+    (204, c"S-expression not canonical"),
+    (205, c"Bad character in S-expression"),
+    (206, c"Bad quotation in S-expression"),
+    (207, c"Zero prefix in S-expression"),
+    (208, c"Nested display hints in S-expression"),
+    (209, c"Unmatched display hints"),
+    (210, c"Unexpected reserved punctuation in S-expression"),
+    (211, c"Bad hexadecimal character in S-expression"),
+    (212, c"Odd hexadecimal numbers in S-expression"),
+    (213, c"Bad octal character in S-expression"),
+    (217, c"All subkeys are expired or revoked"),
+    (218, c"Database is corrupted"),
+    (219, c"Server indicated a failure"),
+    (220, c"No name"),
+    (221, c"No key"),
+    (222, c"Legacy key"),
+    (223, c"Request too short"),
+    (224, c"Request too long"),
+    (225, c"Object is in termination state"),
+    (226, c"No certificate chain"),
+    (227, c"Certificate is too large"),
+    (228, c"Invalid record"),
+    (229, c"The MAC does not verify"),
+    (230, c"Unexpected message"),
+    (231, c"Compression or decompression failed"),
+    (232, c"A counter would wrap"),
+    (233, c"Fatal alert message received"),
+    (234, c"No cipher algorithm"),
+    (235, c"Missing client certificate"),
+    (236, c"Close notification received"),
+    (237, c"Ticket expired"),
+    (238, c"Bad ticket"),
+    (239, c"Unknown identity"),
+    (240, c"Bad certificate message in handshake"),
+    (241, c"Bad certificate request message in handshake"),
+    (242, c"Bad certificate verify message in handshake"),
+    (243, c"Bad change cipher message in handshake"),
+    (244, c"Bad client hello message in handshake"),
+    (245, c"Bad server hello message in handshake"),
+    (246, c"Bad server hello done message in handshake"),
+    (247, c"Bad finished message in handshake"),
+    (248, c"Bad server key exchange message in handshake"),
+    (249, c"Bad client key exchange message in handshake"),
+    (250, c"Bogus string"),
+    (251, c"Forbidden"),
+    (252, c"Key disabled"),
+    (253, c"Not possible with a card based key"),
+    (254, c"Invalid lock object"),
+    (255, c"True"),
+    (256, c"False"),
+    (257, c"General IPC error"),
+    (258, c"IPC accept call failed"),
+    (259, c"IPC connect call failed"),
+    (260, c"Invalid IPC response"),
+    (261, c"Invalid value passed to IPC"),
+    (262, c"Incomplete line passed to IPC"),
+    (263, c"Line passed to IPC too long"),
+    (264, c"Nested IPC commands"),
+    (265, c"No data callback in IPC"),
+    (266, c"No inquire callback in IPC"),
+    (267, c"Not an IPC server"),
+    (268, c"Not an IPC client"),
+    (269, c"Problem starting IPC server"),
+    (270, c"IPC read error"),
+    (271, c"IPC write error"),
+    (273, c"Too much data for IPC layer"),
+    (274, c"Unexpected IPC command"),
+    (275, c"Unknown IPC command"),
+    (276, c"IPC syntax error"),
+    (277, c"IPC call has been cancelled"),
+    (278, c"No input source for IPC"),
+    (279, c"No output source for IPC"),
+    (280, c"IPC parameter error"),
+    (281, c"Unknown IPC inquire"),
+    (300, c"Crypto engine too old"),
+    (301, c"Screen or window too small"),
+    (302, c"Screen or window too large"),
+    (303, c"Required environment variable not set"),
+    (304, c"User ID already exists"),
+    (305, c"Name already exists"),
+    (306, c"Duplicated name"),
+    (307, c"Object is too young"),
+    (308, c"Object is too old"),
+    (309, c"Unknown flag"),
+    (310, c"Invalid execution order"),
+    (311, c"Already fetched"),
+    (312, c"Try again later"),
+    (313, c"Wrong name"),
+    (314, c"Not authenticated"),
+    (315, c"Bad authentication"),
+    (316, c"No Keybox daemon running"),
+    (317, c"Keybox daemon error"),
+    (318, c"Service is not running"),
+    (319, c"Service error"),
+    (320, c"Bad PUK"),
+    (321, c"No reset code"),
+    (322, c"Bad reset code"),
+    (666, c"System bug detected"),
+    (711, c"Unknown DNS error"),
+    (712, c"Invalid DNS section"),
+    (713, c"Invalid textual address form"),
+    (714, c"Missing DNS query packet"),
+    (715, c"Missing DNS answer packet"),
+    (716, c"Connection closed in DNS"),
+    (717, c"Verification failed in DNS"),
+    (718, c"DNS Timeout"),
+    (721, c"General LDAP error"),
+    (722, c"General LDAP attribute error"),
+    (723, c"General LDAP name error"),
+    (724, c"General LDAP security error"),
+    (725, c"General LDAP service error"),
+    (726, c"General LDAP update error"),
+    (727, c"Experimental LDAP error code"),
+    (728, c"Private LDAP error code"),
+    (729, c"Other general LDAP error"),
+    (750, c"LDAP connecting failed (X)"),
+    (751, c"LDAP referral limit exceeded"),
+    (752, c"LDAP client loop"),
+    (754, c"No LDAP results returned"),
+    (755, c"LDAP control not found"),
+    (756, c"Not supported by LDAP"),
+    (757, c"LDAP connect error"),
+    (758, c"Out of memory in LDAP"),
+    (759, c"Bad parameter to an LDAP routine"),
+    (760, c"User cancelled LDAP operation"),
+    (761, c"Bad LDAP search filter"),
+    (762, c"Unknown LDAP authentication method"),
+    (763, c"Timeout in LDAP"),
+    (764, c"LDAP decoding error"),
+    (765, c"LDAP encoding error"),
+    (766, c"LDAP local error"),
+    (767, c"Cannot contact LDAP server"),
+    (768, c"LDAP success"),
+    (769, c"LDAP operations error"),
+    (770, c"LDAP protocol error"),
+    (771, c"Time limit exceeded in LDAP"),
+    (772, c"Size limit exceeded in LDAP"),
+    (773, c"LDAP compare false"),
+    (774, c"LDAP compare true"),
+    (775, c"LDAP authentication method not supported"),
+    (776, c"Strong(er) LDAP authentication required"),
+    (777, c"Partial LDAP results+referral received"),
+    (778, c"LDAP referral"),
+    (779, c"Administrative LDAP limit exceeded"),
+    (780, c"Critical LDAP extension is unavailable"),
+    (781, c"Confidentiality required by LDAP"),
+    (782, c"LDAP SASL bind in progress"),
+    (784, c"No such LDAP attribute"),
+    (785, c"Undefined LDAP attribute type"),
+    (786, c"Inappropriate matching in LDAP"),
+    (787, c"Constraint violation in LDAP"),
+    (788, c"LDAP type or value exists"),
+    (789, c"Invalid syntax in LDAP"),
+    (800, c"No such LDAP object"),
+    (801, c"LDAP alias problem"),
+    (802, c"Invalid DN syntax in LDAP"),
+    (803, c"LDAP entry is a leaf"),
+    (804, c"LDAP alias dereferencing problem"),
+    (815, c"LDAP proxy authorization failure (X)"),
+    (816, c"Inappropriate LDAP authentication"),
+    (817, c"Invalid LDAP credentials"),
+    (818, c"Insufficient access for LDAP"),
+    (819, c"LDAP server is busy"),
+    (820, c"LDAP server is unavailable"),
+    (821, c"LDAP server is unwilling to perform"),
+    (822, c"Loop detected by LDAP"),
+    (832, c"LDAP naming violation"),
+    (833, c"LDAP object class violation"),
+    (834, c"LDAP operation not allowed on non-leaf"),
+    (835, c"LDAP operation not allowed on RDN"),
+    (836, c"Already exists (LDAP)"),
+    (837, c"Cannot modify LDAP object class"),
+    (838, c"LDAP results too large"),
+    (839, c"LDAP operation affects multiple DSAs"),
+    (844, c"Virtual LDAP list view error"),
+    (848, c"Other LDAP error"),
+    (881, c"Resources exhausted in LCUP"),
+    (882, c"Security violation in LCUP"),
+    (883, c"Invalid data in LCUP"),
+    (884, c"Unsupported scheme in LCUP"),
+    (885, c"Reload required in LCUP"),
+    (886, c"LDAP cancelled"),
+    (887, c"No LDAP operation to cancel"),
+    (888, c"Too late to cancel LDAP"),
+    (889, c"Cannot cancel LDAP"),
+    (890, c"LDAP assertion failed"),
+    (891, c"Proxied authorization denied by LDAP"),
+    (1024, c"User defined error code 1"),
+    (1025, c"User defined error code 2"),
+    (1026, c"User defined error code 3"),
+    (1027, c"User defined error code 4"),
+    (1028, c"User defined error code 5"),
+    (1029, c"User defined error code 6"),
+    (1030, c"User defined error code 7"),
+    (1031, c"User defined error code 8"),
+    (1032, c"User defined error code 9"),
+    (1033, c"User defined error code 10"),
+    (1034, c"User defined error code 11"),
+    (1035, c"User defined error code 12"),
+    (1036, c"User defined error code 13"),
+    (1037, c"User defined error code 14"),
+    (1038, c"User defined error code 15"),
+    (1039, c"User defined error code 16"),
+    (1500, c"SQL success"),
+    (1501, c"SQL error"),
+    (1502, c"Internal logic error in SQL library"),
+    (1503, c"Access permission denied (SQL)"),
+    (1504, c"SQL abort was requested"),
+    (1505, c"SQL database file is locked"),
+    (1506, c"An SQL table in the database is locked"),
+    (1507, c"SQL library ran out of core"),
+    (1508, c"Attempt to write a readonly SQL database"),
+    (1509, c"SQL operation terminated by interrupt"),
+    (1510, c"I/O error during SQL operation"),
+    (1511, c"SQL database disk image is malformed"),
+    (1512, c"Unknown opcode in SQL file control"),
+    (1513, c"Insertion failed because SQL database is full"),
+    (1514, c"Unable to open the SQL database file"),
+    (1515, c"SQL database lock protocol error"),
+    (1516, c"(internal SQL code: empty)"),
+    (1517, c"SQL database schema changed"),
+    (1518, c"String or blob exceeds size limit (SQL)"),
+    (1519, c"SQL abort due to constraint violation"),
+    (1520, c"Data type mismatch (SQL)"),
+    (1521, c"SQL library used incorrectly"),
+    (1522, c"SQL library uses unsupported OS features"),
+    (1523, c"Authorization denied (SQL)"),
+    (1524, c"(unused SQL code: format)"),
+    (1525, c"SQL bind parameter out of range"),
+    (1526, c"File opened that is not an SQL database file"),
+    (1527, c"Notifications from SQL logger"),
+    (1528, c"Warnings from SQL logger"),
+    (1600, c"SQL has another row ready"),
+    (1601, c"SQL has finished executing"),
+    (16381, c"System error w/o errno"),
+    (16382, c"Unknown system error"),
+    (16383, c"End of file"),
+];
+static UNKNOWN_CODE: &CStr = c"Unknown error code";
@@ -0,0 +1,298 @@
+/// Zero for the errno names the target does not define; shadowed by the
+/// constants of the `libc` crate where it does.
+#[allow(dead_code)]
+mod missing {
+    use core::ffi::c_int;
+
+    pub const E2BIG: c_int = 0;
+    pub const EACCES: c_int = 0;
+    pub const EADDRINUSE: c_int = 0;
+    pub const EADDRNOTAVAIL: c_int = 0;
+    pub const EADV: c_int = 0;
+    pub const EAFNOSUPPORT: c_int = 0;
+    pub const EAGAIN: c_int = 0;
+    pub const EALREADY: c_int = 0;
+    pub const EAUTH: c_int = 0;
+    pub const EBACKGROUND: c_int = 0;
+    pub const EBADE: c_int = 0;
+    pub const EBADF: c_int = 0;
+    pub const EBADFD: c_int = 0;
+    pub const EBADMSG: c_int = 0;
+    pub const EBADR: c_int = 0;
+    pub const EBADRPC: c_int = 0;
+    pub const EBADRQC: c_int = 0;
+    pub const EBADSLT: c_int = 0;
+    pub const EBFONT: c_int = 0;
+    pub const EBUSY: c_int = 0;
+    pub const ECANCELED: c_int = 0;
+    pub const ECHILD: c_int = 0;
+    pub const ECHRNG: c_int = 0;
+    pub const ECOMM: c_int = 0;
+    pub const ECONNABORTED: c_int = 0;
+    pub const ECONNREFUSED: c_int = 0;
+    pub const ECONNRESET: c_int = 0;
+    pub const ED: c_int = 0;
+    pub const EDEADLK: c_int = 0;
+    pub const EDEADLOCK: c_int = 0;
+    pub const EDESTADDRREQ: c_int = 0;
+    pub const EDIED: c_int = 0;
+    pub const EDOM: c_int = 0;
+    pub const EDOTDOT: c_int = 0;
+    pub const EDQUOT: c_int = 0;
+    pub const EEXIST: c_int = 0;
+    pub const EFAULT: c_int = 0;
+    pub const EFBIG: c_int = 0;
+    pub const EFTYPE: c_int = 0;
+    pub const EGRATUITOUS: c_int = 0;
+    pub const EGREGIOUS: c_int = 0;
+    pub const EHOSTDOWN: c_int = 0;
+    pub const EHOSTUNREACH: c_int = 0;
+    pub const EIDRM: c_int = 0;
+    pub const EIEIO: c_int = 0;
+    pub const EILSEQ: c_int = 0;
+    pub const EINPROGRESS: c_int = 0;
+    pub const EINTR: c_int = 0;
+    pub const EINVAL: c_int = 0;
+    pub const EIO: c_int = 0;
+    pub const EISCONN: c_int = 0;
+    pub const EISDIR: c_int = 0;
+    pub const EISNAM: c_int = 0;
+    pub const EL2HLT: c_int = 0;
+    pub const EL2NSYNC: c_int = 0;
+    pub const EL3HLT: c_int = 0;
+    pub const EL3RST: c_int = 0;
+    pub const ELIBACC: c_int = 0;
+    pub const ELIBBAD: c_int = 0;
+    pub const ELIBEXEC: c_int = 0;
+    pub const ELIBMAX: c_int = 0;
+    pub const ELIBSCN: c_int = 0;
+    pub const ELNRNG: c_int = 0;
+    pub const ELOOP: c_int = 0;
+    pub const EMEDIUMTYPE: c_int = 0;
+    pub const EMFILE: c_int = 0;
+    pub const EMLINK: c_int = 0;
+    pub const EMSGSIZE: c_int = 0;
+    pub const EMULTIHOP: c_int = 0;
+    pub const ENAMETOOLONG: c_int = 0;
+    pub const ENAVAIL: c_int = 0;
+    pub const ENEEDAUTH: c_int = 0;
+    pub const ENETDOWN: c_int = 0;
+    pub const ENETRESET: c_int = 0;
+    pub const ENETUNREACH: c_int = 0;
+    pub const ENFILE: c_int = 0;
+    pub const ENOANO: c_int = 0;
+    pub const ENOBUFS: c_int = 0;
+    pub const ENOCSI: c_int = 0;
+    pub const ENODATA: c_int = 0;
+    pub const ENODEV: c_int = 0;
+    pub const ENOENT: c_int = 0;
+    pub const ENOEXEC: c_int = 0;
+    pub const ENOLCK: c_int = 0;
+    pub const ENOLINK: c_int = 0;
+    pub const ENOMEDIUM: c_int = 0;
+    pub const ENOMEM: c_int = 0;
+    pub const ENOMSG: c_int = 0;
+    pub const ENONET: c_int = 0;
+    pub const ENOPKG: c_int = 0;
+    pub const ENOPROTOOPT: c_int = 0;
+    pub const ENOSPC: c_int = 0;
+    pub const ENOSR: c_int = 0;
+    pub const ENOSTR: c_int = 0;
+    pub const ENOSYS: c_int = 0;
+    pub const ENOTBLK: c_int = 0;
+    pub const ENOTCONN: c_int = 0;
+    pub const ENOTDIR: c_int = 0;
+    pub const ENOTEMPTY: c_int = 0;
+    pub const ENOTNAM: c_int = 0;
+    pub const ENOTSOCK: c_int = 0;
+    pub const ENOTSUP: c_int = 0;
+    pub const ENOTTY: c_int = 0;
+    pub const ENOTUNIQ: c_int = 0;
+    pub const ENXIO: c_int = 0;
+    pub const EOPNOTSUPP: c_int = 0;
+    pub const EOVERFLOW: c_int = 0;
+    pub const EPERM: c_int = 0;
+    pub const EPFNOSUPPORT: c_int = 0;
+    pub const EPIPE: c_int = 0;
+    pub const EPROCLIM: c_int = 0;
+    pub const EPROCUNAVAIL: c_int = 0;
+    pub const EPROGMISMATCH: c_int = 0;
+    pub const EPROGUNAVAIL: c_int = 0;
+    pub const EPROTO: c_int = 0;
+    pub const EPROTONOSUPPORT: c_int = 0;
+    pub const EPROTOTYPE: c_int = 0;
+    pub const ERANGE: c_int = 0;
+    pub const EREMCHG: c_int = 0;
+    pub const EREMOTE: c_int = 0;
+    pub const EREMOTEIO: c_int = 0;
+    pub const ERESTART: c_int = 0;
+    pub const EROFS: c_int = 0;
+    pub const ERPCMISMATCH: c_int = 0;
+    pub const ESHUTDOWN: c_int = 0;
+    pub const ESOCKTNOSUPPORT: c_int = 0;
+    pub const ESPIPE: c_int = 0;
+    pub const ESRCH: c_int = 0;
+    pub const ESRMNT: c_int = 0;
+    pub const ESTALE: c_int = 0;
+    pub const ESTRPIPE: c_int = 0;
+    pub const ETIME: c_int = 0;
+    pub const ETIMEDOUT: c_int = 0;
+    pub const ETOOMANYREFS: c_int = 0;
+    pub const ETXTBSY: c_int = 0;
+    pub const EUCLEAN: c_int = 0;
+    pub const EUNATCH: c_int = 0;
+    pub const EUSERS: c_int = 0;
+    pub const EWOULDBLOCK: c_int = 0;
+    pub const EXDEV: c_int = 0;
+    pub const EXFULL: c_int = 0;
+}
+
+use self::missing::*;
+
+static ERRNOS: [c_int; 141] = {
+    use libc::*;
+
+    [
+        E2BIG,
+        EACCES,
+        EADDRINUSE,
+        EADDRNOTAVAIL,
+        EADV,
+        EAFNOSUPPORT,
+        EAGAIN,
+        EALREADY,
+        EAUTH,
+        EBACKGROUND,
+        EBADE,
+        EBADF,
+        EBADFD,
+        EBADMSG,
+        EBADR,
+        EBADRPC,
+        EBADRQC,
+        EBADSLT,
+        EBFONT,
+        EBUSY,
+        ECANCELED,
+        ECHILD,
+        ECHRNG,
+        ECOMM,
+        ECONNABORTED,
+        ECONNREFUSED,
+        ECONNRESET,
+        ED,
+        EDEADLK,
+        EDEADLOCK,
+        EDESTADDRREQ,
+        EDIED,
+        EDOM,
+        EDOTDOT,
+        EDQUOT,
+        EEXIST,
+        EFAULT,
+        EFBIG,
+        EFTYPE,
+        EGRATUITOUS,
+        EGREGIOUS,
+        EHOSTDOWN,
+        EHOSTUNREACH,
+        EIDRM,
+        EIEIO,
+        EILSEQ,
+        EINPROGRESS,
+        EINTR,
+        EINVAL,
+        EIO,
+        EISCONN,
+        EISDIR,
+        EISNAM,
+        EL2HLT,
+        EL2NSYNC,
+        EL3HLT,
+        EL3RST,
+        ELIBACC,
+        ELIBBAD,
+        ELIBEXEC,
+        ELIBMAX,
+        ELIBSCN,
+        ELNRNG,
+        ELOOP,
+        EMEDIUMTYPE,
+        EMFILE,
+        EMLINK,
+        EMSGSIZE,
+        EMULTIHOP,
+        ENAMETOOLONG,
+        ENAVAIL,
+        ENEEDAUTH,
+        ENETDOWN,
+        ENETRESET,
+        ENETUNREACH,
+        ENFILE,
+        ENOANO,
+        ENOBUFS,
+        ENOCSI,
+        ENODATA,
+        ENODEV,
+        ENOENT,
+        ENOEXEC,
+        ENOLCK,
+        ENOLINK,
+        ENOMEDIUM,
+        ENOMEM,
+        ENOMSG,
+        ENONET,
+        ENOPKG,
+        ENOPROTOOPT,
+        ENOSPC,
+        ENOSR,
+        ENOSTR,
+        ENOSYS,
+        ENOTBLK,
+        ENOTCONN,
+        ENOTDIR,
+        ENOTEMPTY,
+        ENOTNAM,
+        ENOTSOCK,
+        ENOTSUP,
+        ENOTTY,
+        ENOTUNIQ,
+        ENXIO,
+        EOPNOTSUPP,
+        EOVERFLOW,
+        EPERM,
+        EPFNOSUPPORT,
+        EPIPE,
+        EPROCLIM,
+        EPROCUNAVAIL,
+        EPROGMISMATCH,
+        EPROGUNAVAIL,
+        EPROTO,
+        EPROTONOSUPPORT,
+        EPROTOTYPE,
+        ERANGE,
+        EREMCHG,
+        EREMOTE,
+        EREMOTEIO,
+        ERESTART,
+        EROFS,
+        ERPCMISMATCH,
+        ESHUTDOWN,
+        ESOCKTNOSUPPORT,
+        ESPIPE,
+        ESRCH,
+        ESRMNT,
+        ESTALE,
+        ESTRPIPE,
+        ETIME,
+        ETIMEDOUT,
+        ETOOMANYREFS,
+        ETXTBSY,
+        EUCLEAN,
+        EUNATCH,
+        EUSERS,
+        EWOULDBLOCK,
+        EXDEV,
+        EXFULL,
+    ]
+};
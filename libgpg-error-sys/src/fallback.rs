@@ -0,0 +1,243 @@
+//! Rust implementations of the core libgpg-error functions, used when the
+//! `pure_rust` feature is enabled instead of linking the C library.
+// The functions stand in for the C declarations and keep their signatures.
+#![allow(clippy::missing_safety_doc)]
+use core::{
+    cmp::Ordering,
+    ffi::{c_char, c_int, CStr},
+    ptr,
+};
+
+use crate::{
+    consts::*,
+    funcs::{gpg_err_code, gpg_err_source},
+    types::{gpg_err_code_t, gpg_err_source_t, gpg_error_t},
+};
+
+include!("descriptions.rs");
+include!("errnos.rs");
+
+/// The upstream release the vendored tables were taken from.
+const VERSION: &CStr = c"1.48";
+
+extern "C" {
+    #[cfg_attr(
+        any(
+            target_os = "linux",
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "hurd",
+            target_os = "l4re"
+        ),
+        link_name = "__errno_location"
+    )]
+    #[cfg_attr(
+        any(
+            target_os = "android",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "redox",
+            target_env = "newlib"
+        ),
+        link_name = "__errno"
+    )]
+    #[cfg_attr(
+        any(target_os = "solaris", target_os = "illumos"),
+        link_name = "___errno"
+    )]
+    #[cfg_attr(
+        any(target_os = "freebsd", target_vendor = "apple"),
+        link_name = "__error"
+    )]
+    #[cfg_attr(target_os = "dragonfly", link_name = "__dfly_error")]
+    #[cfg_attr(target_os = "haiku", link_name = "_errnop")]
+    #[cfg_attr(target_os = "aix", link_name = "_Errno")]
+    #[cfg_attr(windows, link_name = "_errno")]
+    fn errno_location() -> *mut c_int;
+}
+
+fn lookup<T: Ord + Copy>(table: &[(T, &'static CStr)], key: T) -> Option<&'static CStr> {
+    table
+        .binary_search_by_key(&key, |&(k, _)| k)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+fn code_description(code: gpg_err_code_t) -> &'static CStr {
+    lookup(CODE_DESCRIPTIONS, code).unwrap_or(UNKNOWN_CODE)
+}
+
+/// Copies `s` and a terminating nul into `buf`, truncating if necessary.
+///
+/// Returns `0` if the whole string fit and `ERANGE` otherwise.
+unsafe fn copy_str(s: &CStr, buf: *mut c_char, buflen: usize) -> c_int {
+    let s = s.to_bytes_with_nul();
+    let len = s.len().min(buflen);
+    if len == 0 {
+        return gpg_err_code_to_errno(GPG_ERR_ERANGE);
+    }
+    ptr::copy_nonoverlapping(s.as_ptr().cast(), buf, len);
+    *buf.add(len - 1) = 0;
+    if len == s.len() {
+        0
+    } else {
+        gpg_err_code_to_errno(GPG_ERR_ERANGE)
+    }
+}
+
+pub unsafe extern "C" fn gpg_err_init() -> gpg_error_t {
+    GPG_ERR_NO_ERROR
+}
+
+pub unsafe extern "C" fn gpg_err_deinit(_mode: c_int) {}
+
+pub unsafe extern "C" fn gpg_strerror(err: gpg_error_t) -> *const c_char {
+    let code = gpg_err_code(err);
+    if (code & GPG_ERR_SYSTEM_ERROR) != 0 {
+        let no = gpg_err_code_to_errno(code);
+        if no != 0 {
+            return libc::strerror(no);
+        }
+        return code_description(GPG_ERR_UNKNOWN_ERRNO).as_ptr();
+    }
+    code_description(code).as_ptr()
+}
+
+pub unsafe extern "C" fn gpg_strerror_r(
+    err: gpg_error_t,
+    buf: *mut c_char,
+    buflen: usize,
+) -> c_int {
+    copy_str(CStr::from_ptr(gpg_strerror(err)), buf, buflen)
+}
+
+pub unsafe extern "C" fn gpg_strsource(err: gpg_error_t) -> *const c_char {
+    let source: gpg_err_source_t = gpg_err_source(err);
+    lookup(SOURCE_DESCRIPTIONS, source)
+        .unwrap_or(UNKNOWN_SOURCE)
+        .as_ptr()
+}
+
+pub unsafe extern "C" fn gpg_err_code_from_errno(err: c_int) -> gpg_err_code_t {
+    if err == 0 {
+        return GPG_ERR_NO_ERROR;
+    }
+    match ERRNOS.iter().position(|&e| e == err) {
+        Some(idx) => GPG_ERR_SYSTEM_ERROR | (idx as gpg_err_code_t),
+        None => GPG_ERR_UNKNOWN_ERRNO,
+    }
+}
+
+pub unsafe extern "C" fn gpg_err_code_to_errno(code: gpg_err_code_t) -> c_int {
+    if (code & GPG_ERR_SYSTEM_ERROR) == 0 {
+        return 0;
+    }
+    ERRNOS
+        .get((code & !GPG_ERR_SYSTEM_ERROR) as usize)
+        .copied()
+        .unwrap_or(0)
+}
+
+pub unsafe extern "C" fn gpg_err_code_from_syserror() -> gpg_err_code_t {
+    match *errno_location() {
+        0 => GPG_ERR_MISSING_ERRNO,
+        err => gpg_err_code_from_errno(err),
+    }
+}
+
+pub unsafe extern "C" fn gpg_err_set_errno(err: c_int) {
+    *errno_location() = err;
+}
+
+/// Parses a version number without leading zeros from the start of `s`.
+fn parse_version_number(s: &[u8]) -> Option<(u32, &[u8])> {
+    let digits = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 || (digits > 1 && s[0] == b'0') {
+        return None;
+    }
+    let n = s[..digits].iter().try_fold(0u32, |n, &d| {
+        n.checked_mul(10)?.checked_add(u32::from(d - b'0'))
+    })?;
+    Some((n, &s[digits..]))
+}
+
+/// Parses the first `level` parts of a `major.minor.micro` version string,
+/// returning them and the remaining patch level.
+fn parse_version_string(s: &[u8], level: usize) -> Option<([u32; 3], &[u8])> {
+    let mut parts = [0; 3];
+    let mut rest = s;
+    for (i, part) in parts.iter_mut().enumerate().take(level.clamp(1, 3)) {
+        if i > 0 {
+            rest = rest.strip_prefix(b".")?;
+        }
+        (*part, rest) = parse_version_number(rest)?;
+    }
+    if level < 3 {
+        rest = rest.strip_prefix(b".").unwrap_or(rest);
+    }
+    Some((parts, rest))
+}
+
+pub unsafe extern "C" fn gpg_error_check_version(req_version: *const c_char) -> *const c_char {
+    if req_version.is_null() {
+        return VERSION.as_ptr();
+    }
+    if gpgrt_cmp_version(VERSION.as_ptr(), req_version, 12) >= 0 {
+        VERSION.as_ptr()
+    } else {
+        ptr::null()
+    }
+}
+
+pub unsafe extern "C" fn gpgrt_cmp_version(
+    a: *const c_char,
+    b: *const c_char,
+    level: c_int,
+) -> c_int {
+    let (positive, negative) = if level < 0 { (-1, 1) } else { (1, -1) };
+    let level = level.unsigned_abs() as usize;
+    let ignore_plvl = level > 9;
+    let level = level % 10;
+    let parse = |s: *const c_char| {
+        if s.is_null() {
+            return None;
+        }
+        parse_version_string(CStr::from_ptr(s).to_bytes(), level)
+    };
+
+    let (a, b) = (parse(a), parse(b));
+    if !ignore_plvl {
+        match (&a, &b) {
+            (None, _) => return negative,
+            (Some(_), None) => return positive,
+            _ => (),
+        }
+    }
+    let (a_parts, a_plvl) = a.unwrap_or_default();
+    let (b_parts, b_plvl) = b.unwrap_or_default();
+    match a_parts.cmp(&b_parts) {
+        Ordering::Greater => return positive,
+        Ordering::Less => return negative,
+        Ordering::Equal if ignore_plvl => return 0,
+        Ordering::Equal => (),
+    }
+
+    // Patch levels are compared as strings, except that a dot sorts before
+    // any other character unless both continue with one.
+    let common = a_plvl
+        .iter()
+        .zip(b_plvl)
+        .take_while(|&(x, y)| x == y && *x != b'.')
+        .count();
+    let ordering = match (a_plvl.get(common), b_plvl.get(common)) {
+        (Some(b'.'), Some(b'.')) => a_plvl.cmp(b_plvl),
+        (Some(b'.'), Some(_)) => Ordering::Less,
+        (Some(_), Some(b'.')) => Ordering::Greater,
+        (x, y) => x.map_or(0, |&c| c as i8).cmp(&y.map_or(0, |&c| c as i8)),
+    };
+    match ordering {
+        Ordering::Greater => positive,
+        Ordering::Less => negative,
+        Ordering::Equal => 0,
+    }
+}
@@ -0,0 +1,358 @@
+//! Compile-time checks of the hand-written declarations against the ones
+//! generated from the installed headers.
+use core::{
+    ffi::c_void,
+    mem::{align_of, size_of},
+};
+
+use crate::{bindings as gen, *};
+
+macro_rules! assert_layout {
+    ($($ty:ident),* $(,)?) => {$(
+        const _: () = assert!(
+            size_of::<$ty>() == size_of::<gen::$ty>() && align_of::<$ty>() == align_of::<gen::$ty>(),
+            concat!("layout of ", stringify!($ty), " does not match gpg-error.h"),
+        );
+    )*};
+}
+
+macro_rules! assert_value {
+    ($($name:ident),* $(,)?) => {$(
+        const _: () = assert!(
+            $name as i64 == gen::$name as i64,
+            concat!("value of ", stringify!($name), " does not match gpg-error.h"),
+        );
+    )*};
+}
+
+/// Maps a type used in declarations to a representation shared by the
+/// ABI-compatible types of the hand-written and generated bindings.
+///
+/// Integers are only compared by size, as enums are `int` or `unsigned int`
+/// depending on the compiler and `size_t`-like types are `usize` in one and
+/// `c_ulong` in the other. Opaque and struct types of the generated bindings
+/// map to the hand-written ones, whose layouts are checked separately.
+trait Abi {
+    type Repr;
+}
+
+#[cfg(target_pointer_width = "64")]
+type Word = u64;
+#[cfg(target_pointer_width = "32")]
+type Word = u32;
+
+macro_rules! impl_abi {
+    ($($ty:ty => $repr:ty),* $(,)?) => {$(
+        impl Abi for $ty {
+            type Repr = $repr;
+        }
+    )*};
+}
+
+impl_abi!(
+    () => (),
+    c_void => c_void,
+    i8 => u8,
+    u8 => u8,
+    i16 => u16,
+    u16 => u16,
+    i32 => u32,
+    u32 => u32,
+    i64 => u64,
+    u64 => u64,
+    isize => Word,
+    usize => Word,
+    _gpgrt__stream => _gpgrt__stream,
+    gen::_gpgrt__stream => _gpgrt__stream,
+    gpgrt_cookie_io_functions_t => gpgrt_cookie_io_functions_t,
+    gen::gpgrt_cookie_io_functions_t => gpgrt_cookie_io_functions_t,
+    _gpgrt_b64state => _gpgrt_b64state,
+    gen::_gpgrt_b64state => _gpgrt_b64state,
+);
+#[cfg(gpgrt_lock)]
+impl_abi!(
+    gpgrt_lock_t => gpgrt_lock_t,
+    gen::gpgrt_lock_t => gpgrt_lock_t,
+);
+#[cfg(gpg_error_1_41)]
+impl_abi!(
+    gpgrt_argparse_t => gpgrt_argparse_t,
+    gen::gpgrt_argparse_t => gpgrt_argparse_t,
+    gpgrt_opt_t => gpgrt_opt_t,
+    gen::gpgrt_opt_t => gpgrt_opt_t,
+);
+#[cfg(gpg_error_1_48)]
+impl_abi!(
+    gpgrt_process => gpgrt_process,
+    gen::gpgrt_process => gpgrt_process,
+    gpgrt_spawn_actions => gpgrt_spawn_actions,
+    gen::gpgrt_spawn_actions => gpgrt_spawn_actions,
+);
+#[cfg(gpg_error_1_49)]
+impl_abi!(
+    _gpgrt_name_value_container => _gpgrt_name_value_container,
+    gen::_gpgrt_name_value_container => _gpgrt_name_value_container,
+    _gpgrt_name_value_entry => _gpgrt_name_value_entry,
+    gen::_gpgrt_name_value_entry => _gpgrt_name_value_entry,
+);
+
+impl<T: Abi> Abi for *const T {
+    type Repr = *const T::Repr;
+}
+
+impl<T: Abi> Abi for *mut T {
+    type Repr = *mut T::Repr;
+}
+
+impl<F: Abi> Abi for Option<F> {
+    type Repr = Option<F::Repr>;
+}
+
+macro_rules! impl_abi_fn {
+    ($($arg:ident),*) => {
+        impl<R: Abi, $($arg: Abi),*> Abi for unsafe extern "C" fn($($arg),*) -> R {
+            type Repr = unsafe extern "C" fn($($arg::Repr),*) -> R::Repr;
+        }
+    };
+    ($($arg:ident),+, ...) => {
+        impl<R: Abi, $($arg: Abi),+> Abi for unsafe extern "C" fn($($arg),+, ...) -> R {
+            type Repr = unsafe extern "C" fn($($arg::Repr),+, ...) -> R::Repr;
+        }
+    };
+}
+
+impl_abi_fn!();
+impl_abi_fn!(A);
+impl_abi_fn!(A, B);
+impl_abi_fn!(A, B, C);
+impl_abi_fn!(A, B, C, D);
+impl_abi_fn!(A, B, C, D, E);
+impl_abi_fn!(A, B, C, D, E, F);
+impl_abi_fn!(A, B, C, D, E, F, G);
+impl_abi_fn!(A, ...);
+impl_abi_fn!(A, B, ...);
+impl_abi_fn!(A, B, C, ...);
+
+/// Only compiles if both arguments have the same representation.
+const fn same_abi<A: Abi<Repr = R>, B: Abi<Repr = R>, R>(_: &A, _: &B) {}
+
+macro_rules! assert_type {
+    ($($ty:ident),* $(,)?) => {$(
+        const _: () = {
+            let _ = |a: &$ty, b: &gen::$ty| same_abi(a, b);
+        };
+    )*};
+}
+
+// Diverging functions (`gpgrt_log_bug` and `gpgrt_log_fatal`) cannot be
+// compared, as `!` cannot be named in a trait implementation.
+macro_rules! assert_signature {
+    ($($name:ident($($arg:tt),*)),* $(,)?) => {$(
+        const _: () = same_abi(
+            &($name as unsafe extern "C" fn($($arg),*) -> _),
+            &(gen::$name as unsafe extern "C" fn($($arg),*) -> _),
+        );
+    )*};
+}
+
+assert_layout!(
+    gpg_error_t,
+    gpgrt_ssize_t,
+    gpgrt_off_t,
+    gpgrt_cookie_io_functions_t,
+);
+#[cfg(gpgrt_lock)]
+assert_layout!(gpgrt_lock_t);
+#[cfg(gpg_error_1_41)]
+assert_layout!(gpgrt_argparse_t, gpgrt_opt_t);
+
+assert_value!(
+    GPG_ERR_SOURCE_DIM,
+    GPG_ERR_SOURCE_SHIFT,
+    GPG_ERR_SYSTEM_ERROR,
+    GPG_ERR_CODE_DIM,
+    GPG_ERR_SOURCE_USER_4,
+    GPG_ERR_EOF,
+    GPG_ERR_USER_16,
+    GPG_ERR_EAGAIN,
+);
+#[cfg(gpg_error_1_41)]
+assert_value!(
+    GPGRT_CONFDIR_SYS,
+    ARGPARSE_FLAG_USERVERS,
+    ARGPARSE_TYPE_STRING,
+    ARGPARSE_OPT_CONFFILE,
+    ARGPARSE_INVALID_META,
+);
+
+assert_type!(
+    gpgrt_ssize_t,
+    gpgrt_off_t,
+    gpgrt_stream_t,
+    gpgrt_cookie_read_function_t,
+    gpgrt_cookie_write_function_t,
+    gpgrt_cookie_seek_function_t,
+    gpgrt_cookie_close_function_t,
+    gpgrt_b64state_t,
+);
+#[cfg(gpg_error_1_48)]
+assert_type!(gpgrt_process_t, gpgrt_spawn_actions_t);
+#[cfg(gpg_error_1_49)]
+assert_type!(gpgrt_nvc_t, gpgrt_nve_t);
+
+assert_signature!(
+    gpg_err_init(),
+    gpg_err_deinit(_),
+    gpg_strerror(_),
+    gpg_strerror_r(_, _, _),
+    gpg_strsource(_),
+    gpg_err_code_from_errno(_),
+    gpg_err_code_to_errno(_),
+    gpg_err_code_from_syserror(),
+    gpg_err_set_errno(_),
+    gpg_error_check_version(_),
+);
+#[cfg(gpg_error_1_41)]
+assert_signature!(gpgrt_cmp_version(_, _, _));
+
+// estream
+assert_signature!(
+    gpgrt_fopen(_, _),
+    gpgrt_mopen(_, _, _, _, _, _, _),
+    gpgrt_fopenmem(_, _),
+    gpgrt_fopenmem_init(_, _, _, _),
+    gpgrt_fdopen(_, _),
+    gpgrt_fdopen_nc(_, _),
+    gpgrt_fopencookie(_, _, _),
+    gpgrt_tmpfile(),
+    gpgrt_fclose(_),
+    gpgrt_fcancel(_),
+    gpgrt_fclose_snatch(_, _, _),
+    gpgrt_fileno(_),
+    _gpgrt_get_std_stream(_),
+    gpgrt_feof(_),
+    gpgrt_ferror(_),
+    gpgrt_clearerr(_),
+    gpgrt_fflush(_),
+    gpgrt_fseek(_, _, _),
+    gpgrt_fseeko(_, _, _),
+    gpgrt_ftruncate(_, _),
+    gpgrt_ftell(_),
+    gpgrt_ftello(_),
+    gpgrt_rewind(_),
+    gpgrt_fgetc(_),
+    gpgrt_fputc(_, _),
+    gpgrt_ungetc(_, _),
+    gpgrt_read(_, _, _, _),
+    gpgrt_write(_, _, _, _),
+    gpgrt_fread(_, _, _, _),
+    gpgrt_fwrite(_, _, _, _),
+    gpgrt_fputs(_, _),
+    gpgrt_set_binary(_),
+    gpgrt_set_nonblock(_, _),
+    gpgrt_get_nonblock(_),
+    gpgrt_fname_set(_, _),
+    gpgrt_fname_get(_),
+    gpgrt_free(_),
+);
+
+// log
+assert_signature!(
+    gpgrt_log_set_sink(_, _, _),
+    gpgrt_log_set_socket_dir_cb(_),
+    gpgrt_log_set_pid_suffix_cb(_),
+    gpgrt_log_set_prefix(_, _),
+    gpgrt_get_errorcount(_),
+    gpgrt_inc_errorcount(),
+    gpgrt_log_get_prefix(_),
+    gpgrt_log_test_fd(_),
+    gpgrt_log_get_fd(),
+    gpgrt_log_get_stream(),
+    gpgrt_log(_, _, ...),
+    gpgrt_log_string(_, _),
+    gpgrt_log_error(_, ...),
+    gpgrt_log_info(_, ...),
+    gpgrt_log_debug(_, ...),
+    gpgrt_log_debug_string(_, _, ...),
+    gpgrt_log_printf(_, ...),
+    gpgrt_log_printhex(_, _, _, ...),
+    gpgrt_log_clock(_, ...),
+    gpgrt_log_flush(),
+);
+
+#[cfg(gpg_error_1_41)]
+assert_signature!(
+    gpgrt_argparse(_, _, _),
+    gpgrt_argparser(_, _, _),
+    gpgrt_usage(_),
+    gpgrt_strusage(_),
+    gpgrt_set_strusage(_),
+    gpgrt_set_usage_outfnc(_),
+    gpgrt_set_fixed_string_mapper(_),
+    gpgrt_set_confdir(_, _),
+);
+
+assert_signature!(
+    gpgrt_b64enc_start(_, _),
+    gpgrt_b64enc_write(_, _, _),
+    gpgrt_b64enc_finish(_),
+    gpgrt_b64dec_start(_),
+    gpgrt_b64dec_proc(_, _, _, _),
+    gpgrt_b64dec_finish(_),
+);
+
+#[cfg(gpgrt_lock)]
+assert_signature!(
+    gpgrt_lock_init(_),
+    gpgrt_lock_lock(_),
+    gpgrt_lock_trylock(_),
+    gpgrt_lock_unlock(_),
+    gpgrt_lock_destroy(_),
+    gpgrt_yield(),
+);
+
+#[cfg(gpg_error_1_48)]
+assert_signature!(
+    gpgrt_spawn_actions_new(_),
+    gpgrt_spawn_actions_release(_),
+    gpgrt_process_spawn(_, _, _, _, _),
+    gpgrt_process_terminate(_),
+    gpgrt_process_get_fds(_, _, _, _, _),
+    gpgrt_process_get_streams(_, _, _, _, _),
+    gpgrt_process_ctl(_, _, ...),
+    gpgrt_process_wait(_, _),
+    gpgrt_process_release(_),
+    gpgrt_process_wait_list(_, _, _),
+);
+#[cfg(all(gpg_error_1_48, windows))]
+assert_signature!(
+    gpgrt_spawn_actions_set_envvars(_, _),
+    gpgrt_spawn_actions_set_redirect(_, _, _, _),
+    gpgrt_spawn_actions_set_inherit_handles(_, _),
+);
+#[cfg(all(gpg_error_1_48, not(windows)))]
+assert_signature!(
+    gpgrt_spawn_actions_set_environ(_, _),
+    gpgrt_spawn_actions_set_atfork(_, _, _),
+    gpgrt_spawn_actions_set_redirect(_, _, _, _),
+    gpgrt_spawn_actions_set_inherit_fds(_, _),
+);
+
+#[cfg(gpg_error_1_49)]
+assert_signature!(
+    gpgrt_nvc_new(_),
+    gpgrt_nvc_release(_),
+    gpgrt_nvc_get_flag(_, _, _),
+    gpgrt_nvc_add(_, _, _),
+    gpgrt_nvc_set(_, _, _),
+    gpgrt_nve_set(_, _, _),
+    gpgrt_nvc_delete(_, _, _),
+    gpgrt_nvc_lookup(_, _),
+    gpgrt_nve_next(_, _),
+    gpgrt_nvc_get_string(_, _),
+    gpgrt_nvc_get_bool(_, _),
+    gpgrt_nve_name(_),
+    gpgrt_nve_value(_),
+    gpgrt_nvc_parse(_, _, _, _),
+    gpgrt_nvc_write(_, _),
+);
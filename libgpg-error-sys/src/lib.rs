@@ -1,13 +1,66 @@
 #![allow(nonstandard_style)]
 #![no_std]
-pub use self::{consts::*, funcs::*, types::*};
+#[cfg(gpg_error_1_41)]
+pub use self::argparse::*;
+#[cfg(gpgrt_lock)]
+pub use self::lock::*;
+#[cfg(gpg_error_1_49)]
+pub use self::nvc::*;
+#[cfg(gpg_error_1_48)]
+pub use self::process::*;
+pub use self::{b64::*, consts::*, estream::*, funcs::*, log::*, types::*};
+
+#[cfg(feature = "pure_rust")]
+mod fallback;
+#[cfg(all(feature = "bindgen", not(feature = "pure_rust")))]
+mod layout;
+
+/// Declarations generated from the installed `gpg-error.h` by the `bindgen`
+/// feature, covering the whole gpgrt API.
+///
+/// Types in this module are distinct from the ones declared at the crate
+/// root, even where their layouts match.
+#[cfg(all(feature = "bindgen", not(feature = "pure_rust")))]
+pub mod bindings {
+    #![allow(clippy::all, rustdoc::all)]
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 
 pub mod types {
-    use core::ffi::c_uint;
+    use core::ffi::{c_int, c_uint, c_void};
 
     pub type gpg_error_t = c_uint;
     pub type gpg_err_source_t = c_uint;
     pub type gpg_err_code_t = c_uint;
+
+    pub type gpgrt_ssize_t = isize;
+    #[cfg(all(windows, target_pointer_width = "64"))]
+    pub type gpgrt_off_t = i64;
+    #[cfg(not(all(windows, target_pointer_width = "64")))]
+    pub type gpgrt_off_t = core::ffi::c_long;
+
+    #[repr(C)]
+    pub struct _gpgrt__stream {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_stream_t = *mut _gpgrt__stream;
+
+    pub type gpgrt_cookie_read_function_t =
+        Option<unsafe extern "C" fn(*mut c_void, *mut c_void, usize) -> gpgrt_ssize_t>;
+    pub type gpgrt_cookie_write_function_t =
+        Option<unsafe extern "C" fn(*mut c_void, *const c_void, usize) -> gpgrt_ssize_t>;
+    pub type gpgrt_cookie_seek_function_t =
+        Option<unsafe extern "C" fn(*mut c_void, *mut gpgrt_off_t, c_int) -> c_int>;
+    pub type gpgrt_cookie_close_function_t = Option<unsafe extern "C" fn(*mut c_void) -> c_int>;
+
+    #[repr(C)]
+    #[derive(Copy, Clone, Default)]
+    pub struct gpgrt_cookie_io_functions_t {
+        pub func_read: gpgrt_cookie_read_function_t,
+        pub func_write: gpgrt_cookie_write_function_t,
+        pub func_seek: gpgrt_cookie_seek_function_t,
+        pub func_close: gpgrt_cookie_close_function_t,
+    }
 }
 
 pub mod consts {
@@ -21,11 +74,21 @@ pub mod consts {
     pub const GPG_ERR_CODE_DIM: gpg_err_code_t = 65536;
     pub const GPG_ERR_CODE_MASK: gpg_error_t = (GPG_ERR_CODE_DIM as gpg_error_t) - 1;
 
-    include!("consts.rs");
+    // Generated by tools/mkerrcodes.py, which writes every errno code as
+    // `GPG_ERR_SYSTEM_ERROR | n`, including `n = 0`.
+    #[allow(clippy::identity_op)]
+    mod generated {
+        use super::*;
+
+        include!("consts.rs");
+    }
+    pub use self::generated::*;
 }
 
 pub mod funcs {
-    use core::ffi::{c_char, c_int};
+    #[cfg(not(feature = "pure_rust"))]
+    use core::ffi::c_char;
+    use core::ffi::c_int;
 
     use crate::types::{gpg_err_code_t, gpg_err_source_t, gpg_error_t};
 
@@ -52,21 +115,38 @@ pub mod funcs {
         (err >> GPG_ERR_SOURCE_SHIFT) & GPG_ERR_SOURCE_MASK
     }
 
+    /// # Safety
+    ///
+    /// Same as [`gpg_err_code_from_errno`].
     #[inline]
     pub unsafe fn gpg_err_make_from_errno(source: gpg_err_source_t, err: c_int) -> gpg_error_t {
         gpg_err_make(source, gpg_err_code_from_errno(err))
     }
 
+    /// # Safety
+    ///
+    /// Same as [`gpg_err_code_from_errno`].
     #[inline]
     pub unsafe fn gpg_error_from_errno(err: c_int) -> gpg_error_t {
         gpg_err_make_from_errno(GPG_ERR_SOURCE_UNKNOWN, err)
     }
 
+    /// # Safety
+    ///
+    /// Same as [`gpg_err_code_from_syserror`].
     #[inline]
     pub unsafe fn gpg_error_from_syserror() -> gpg_error_t {
         gpg_err_make(GPG_ERR_SOURCE_UNKNOWN, gpg_err_code_from_syserror())
     }
 
+    #[cfg(feature = "pure_rust")]
+    pub use crate::fallback::{
+        gpg_err_code_from_errno, gpg_err_code_from_syserror, gpg_err_code_to_errno, gpg_err_deinit,
+        gpg_err_init, gpg_err_set_errno, gpg_error_check_version, gpg_strerror, gpg_strerror_r,
+        gpg_strsource, gpgrt_cmp_version,
+    };
+
+    #[cfg(not(feature = "pure_rust"))]
     #[cfg_attr(
         all(windows, feature = "windows_raw_dylib"),
         link(
@@ -92,4 +172,599 @@ pub mod funcs {
 
         pub fn gpg_error_check_version(req_version: *const c_char) -> *const c_char;
     }
+
+    #[cfg(all(gpg_error_1_41, not(feature = "pure_rust")))]
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_cmp_version(a: *const c_char, b: *const c_char, level: c_int) -> c_int;
+    }
+}
+
+pub mod estream {
+    use core::ffi::{c_char, c_int, c_long, c_void};
+
+    use crate::types::{gpgrt_cookie_io_functions_t, gpgrt_off_t, gpgrt_stream_t};
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_fopen(path: *const c_char, mode: *const c_char) -> gpgrt_stream_t;
+        pub fn gpgrt_mopen(
+            data: *mut c_void,
+            data_n: usize,
+            data_len: usize,
+            grow: u32,
+            func_realloc: Option<unsafe extern "C" fn(*mut c_void, usize) -> *mut c_void>,
+            func_free: Option<unsafe extern "C" fn(*mut c_void)>,
+            mode: *const c_char,
+        ) -> gpgrt_stream_t;
+        pub fn gpgrt_fopenmem(memlimit: usize, mode: *const c_char) -> gpgrt_stream_t;
+        pub fn gpgrt_fopenmem_init(
+            memlimit: usize,
+            mode: *const c_char,
+            data: *const c_void,
+            datalen: usize,
+        ) -> gpgrt_stream_t;
+        pub fn gpgrt_fdopen(filedes: c_int, mode: *const c_char) -> gpgrt_stream_t;
+        pub fn gpgrt_fdopen_nc(filedes: c_int, mode: *const c_char) -> gpgrt_stream_t;
+        pub fn gpgrt_fopencookie(
+            cookie: *mut c_void,
+            mode: *const c_char,
+            functions: gpgrt_cookie_io_functions_t,
+        ) -> gpgrt_stream_t;
+        pub fn gpgrt_tmpfile() -> gpgrt_stream_t;
+        pub fn gpgrt_fclose(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_fcancel(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_fclose_snatch(
+            stream: gpgrt_stream_t,
+            r_buffer: *mut *mut c_void,
+            r_buflen: *mut usize,
+        ) -> c_int;
+        pub fn gpgrt_fileno(stream: gpgrt_stream_t) -> c_int;
+
+        pub fn _gpgrt_get_std_stream(fd: c_int) -> gpgrt_stream_t;
+
+        pub fn gpgrt_feof(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_ferror(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_clearerr(stream: gpgrt_stream_t);
+
+        pub fn gpgrt_fflush(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_fseek(stream: gpgrt_stream_t, offset: c_long, whence: c_int) -> c_int;
+        pub fn gpgrt_fseeko(stream: gpgrt_stream_t, offset: gpgrt_off_t, whence: c_int) -> c_int;
+        pub fn gpgrt_ftruncate(stream: gpgrt_stream_t, length: gpgrt_off_t) -> c_int;
+        pub fn gpgrt_ftell(stream: gpgrt_stream_t) -> c_long;
+        pub fn gpgrt_ftello(stream: gpgrt_stream_t) -> gpgrt_off_t;
+        pub fn gpgrt_rewind(stream: gpgrt_stream_t);
+
+        pub fn gpgrt_fgetc(stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_fputc(c: c_int, stream: gpgrt_stream_t) -> c_int;
+        pub fn gpgrt_ungetc(c: c_int, stream: gpgrt_stream_t) -> c_int;
+
+        pub fn gpgrt_read(
+            stream: gpgrt_stream_t,
+            buffer: *mut c_void,
+            bytes_to_read: usize,
+            bytes_read: *mut usize,
+        ) -> c_int;
+        pub fn gpgrt_write(
+            stream: gpgrt_stream_t,
+            buffer: *const c_void,
+            bytes_to_write: usize,
+            bytes_written: *mut usize,
+        ) -> c_int;
+        pub fn gpgrt_fread(
+            ptr: *mut c_void,
+            size: usize,
+            nitems: usize,
+            stream: gpgrt_stream_t,
+        ) -> usize;
+        pub fn gpgrt_fwrite(
+            ptr: *const c_void,
+            size: usize,
+            nitems: usize,
+            stream: gpgrt_stream_t,
+        ) -> usize;
+        pub fn gpgrt_fputs(s: *const c_char, stream: gpgrt_stream_t) -> c_int;
+
+        pub fn gpgrt_set_binary(stream: gpgrt_stream_t);
+        pub fn gpgrt_set_nonblock(stream: gpgrt_stream_t, onoff: c_int) -> c_int;
+        pub fn gpgrt_get_nonblock(stream: gpgrt_stream_t) -> c_int;
+
+        pub fn gpgrt_fname_set(stream: gpgrt_stream_t, fname: *const c_char);
+        pub fn gpgrt_fname_get(stream: gpgrt_stream_t) -> *const c_char;
+
+        pub fn gpgrt_free(a: *mut c_void);
+    }
+}
+
+pub mod log {
+    use core::ffi::{c_char, c_int, c_uint, c_void};
+
+    use crate::types::gpgrt_stream_t;
+
+    pub const GPGRT_LOG_WITH_PREFIX: c_uint = 1;
+    pub const GPGRT_LOG_WITH_TIME: c_uint = 2;
+    pub const GPGRT_LOG_WITH_PID: c_uint = 4;
+    pub const GPGRT_LOG_RUN_DETACHED: c_uint = 256;
+    pub const GPGRT_LOG_NO_REGISTRY: c_uint = 512;
+
+    pub const GPGRT_LOGLVL_BEGIN: c_int = 0;
+    pub const GPGRT_LOGLVL_CONT: c_int = 1;
+    pub const GPGRT_LOGLVL_INFO: c_int = 2;
+    pub const GPGRT_LOGLVL_WARN: c_int = 3;
+    pub const GPGRT_LOGLVL_ERROR: c_int = 4;
+    pub const GPGRT_LOGLVL_FATAL: c_int = 5;
+    pub const GPGRT_LOGLVL_BUG: c_int = 6;
+    pub const GPGRT_LOGLVL_DEBUG: c_int = 7;
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_log_set_sink(name: *const c_char, stream: gpgrt_stream_t, fd: c_int);
+        pub fn gpgrt_log_set_socket_dir_cb(fnc: Option<unsafe extern "C" fn() -> *const c_char>);
+        pub fn gpgrt_log_set_pid_suffix_cb(
+            cb: Option<unsafe extern "C" fn(r_value: *mut core::ffi::c_ulong) -> c_int>,
+        );
+        pub fn gpgrt_log_set_prefix(text: *const c_char, flags: c_uint);
+
+        pub fn gpgrt_get_errorcount(clear: c_int) -> c_int;
+        pub fn gpgrt_inc_errorcount();
+        pub fn gpgrt_log_get_prefix(flags: *mut c_uint) -> *const c_char;
+        pub fn gpgrt_log_test_fd(fd: c_int) -> c_int;
+        pub fn gpgrt_log_get_fd() -> c_int;
+        pub fn gpgrt_log_get_stream() -> gpgrt_stream_t;
+
+        pub fn gpgrt_log(level: c_int, fmt: *const c_char, ...);
+        pub fn gpgrt_log_string(level: c_int, string: *const c_char);
+        pub fn gpgrt_log_bug(fmt: *const c_char, ...) -> !;
+        pub fn gpgrt_log_fatal(fmt: *const c_char, ...) -> !;
+        pub fn gpgrt_log_error(fmt: *const c_char, ...);
+        pub fn gpgrt_log_info(fmt: *const c_char, ...);
+        pub fn gpgrt_log_debug(fmt: *const c_char, ...);
+        pub fn gpgrt_log_debug_string(string: *const c_char, fmt: *const c_char, ...);
+        pub fn gpgrt_log_printf(fmt: *const c_char, ...);
+        pub fn gpgrt_log_printhex(buffer: *const c_void, length: usize, fmt: *const c_char, ...);
+        pub fn gpgrt_log_clock(fmt: *const c_char, ...);
+        pub fn gpgrt_log_flush();
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+pub mod argparse {
+    use core::ffi::{c_char, c_int, c_long, c_uint, c_ulong, c_void};
+
+    use crate::types::gpgrt_stream_t;
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub union gpgrt_argparse_r {
+        pub ret_int: c_int,
+        pub ret_long: c_long,
+        pub ret_ulong: c_ulong,
+        pub ret_str: *mut c_char,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct gpgrt_argparse_t {
+        pub argc: *mut c_int,
+        pub argv: *mut *mut *mut c_char,
+        pub flags: c_uint,
+        pub err: c_int,
+        pub lineno: c_uint,
+        pub r_opt: c_int,
+        pub r_type: c_int,
+        pub r: gpgrt_argparse_r,
+        pub internal: *mut c_void,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct gpgrt_opt_t {
+        pub short_opt: c_int,
+        pub long_opt: *const c_char,
+        pub flags: c_uint,
+        pub description: *const c_char,
+    }
+
+    pub const ARGPARSE_FLAG_KEEP: c_uint = 1;
+    pub const ARGPARSE_FLAG_ALL: c_uint = 2;
+    pub const ARGPARSE_FLAG_MIXED: c_uint = 4;
+    pub const ARGPARSE_FLAG_NOSTOP: c_uint = 8;
+    pub const ARGPARSE_FLAG_ARG0: c_uint = 16;
+    pub const ARGPARSE_FLAG_ONEDASH: c_uint = 32;
+    pub const ARGPARSE_FLAG_NOVERSION: c_uint = 64;
+    pub const ARGPARSE_FLAG_RESET: c_uint = 128;
+    pub const ARGPARSE_FLAG_STOP_SEEN: c_uint = 256;
+    pub const ARGPARSE_FLAG_NOLINENO: c_uint = 512;
+    pub const ARGPARSE_FLAG_SYS: c_uint = 1024;
+    pub const ARGPARSE_FLAG_USER: c_uint = 2048;
+    pub const ARGPARSE_FLAG_VERBOSE: c_uint = 4096;
+    pub const ARGPARSE_FLAG_USERVERS: c_uint = 8192;
+    pub const ARGPARSE_FLAG_WITHATTR: c_uint = 16384;
+
+    pub const ARGPARSE_PRINT_WARNING: c_int = 1;
+    pub const ARGPARSE_PRINT_ERROR: c_int = 2;
+
+    pub const ARGPARSE_IS_ARG: c_int = -1;
+    pub const ARGPARSE_INVALID_OPTION: c_int = -2;
+    pub const ARGPARSE_MISSING_ARG: c_int = -3;
+    pub const ARGPARSE_KEYWORD_TOO_LONG: c_int = -4;
+    pub const ARGPARSE_READ_ERROR: c_int = -5;
+    pub const ARGPARSE_UNEXPECTED_ARG: c_int = -6;
+    pub const ARGPARSE_INVALID_COMMAND: c_int = -7;
+    pub const ARGPARSE_AMBIGUOUS_OPTION: c_int = -8;
+    pub const ARGPARSE_AMBIGUOUS_COMMAND: c_int = -9;
+    pub const ARGPARSE_INVALID_ALIAS: c_int = -10;
+    pub const ARGPARSE_OUT_OF_CORE: c_int = -11;
+    pub const ARGPARSE_INVALID_ARG: c_int = -12;
+    pub const ARGPARSE_PERMISSION_ERROR: c_int = -13;
+    pub const ARGPARSE_NO_CONFFILE: c_int = -14;
+    pub const ARGPARSE_CONFFILE: c_int = -15;
+    pub const ARGPARSE_INVALID_META: c_int = -16;
+    pub const ARGPARSE_UNKNOWN_META: c_int = -17;
+    pub const ARGPARSE_UNEXPECTED_META: c_int = -18;
+
+    pub const ARGPARSE_TYPE_MASK: c_uint = 7;
+    pub const ARGPARSE_TYPE_NONE: c_uint = 0;
+    pub const ARGPARSE_TYPE_INT: c_uint = 1;
+    pub const ARGPARSE_TYPE_STRING: c_uint = 2;
+    pub const ARGPARSE_TYPE_LONG: c_uint = 3;
+    pub const ARGPARSE_TYPE_ULONG: c_uint = 4;
+
+    pub const ARGPARSE_OPT_OPTIONAL: c_uint = 1 << 3;
+    pub const ARGPARSE_OPT_PREFIX: c_uint = 1 << 4;
+    pub const ARGPARSE_OPT_IGNORE: c_uint = 1 << 6;
+    pub const ARGPARSE_OPT_COMMAND: c_uint = 1 << 7;
+    pub const ARGPARSE_OPT_CONFFILE: c_uint = 1 << 8;
+    pub const ARGPARSE_OPT_HEADER: c_uint = 1 << 9;
+    pub const ARGPARSE_OPT_VERBATIM: c_uint = 1 << 10;
+    pub const ARGPARSE_ATTR_FORCE: c_uint = 1 << 14;
+    pub const ARGPARSE_ATTR_IGNORE: c_uint = 1 << 15;
+
+    pub const GPGRT_CONFDIR_USER: c_int = 1;
+    pub const GPGRT_CONFDIR_SYS: c_int = 2;
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_argparse(
+            fp: gpgrt_stream_t,
+            arg: *mut gpgrt_argparse_t,
+            opts: *mut gpgrt_opt_t,
+        ) -> c_int;
+        pub fn gpgrt_argparser(
+            arg: *mut gpgrt_argparse_t,
+            opts: *mut gpgrt_opt_t,
+            confname: *const c_char,
+        ) -> c_int;
+        pub fn gpgrt_usage(level: c_int);
+        pub fn gpgrt_strusage(level: c_int) -> *const c_char;
+        pub fn gpgrt_set_strusage(f: Option<unsafe extern "C" fn(c_int) -> *const c_char>);
+        pub fn gpgrt_set_usage_outfnc(
+            f: Option<unsafe extern "C" fn(c_int, *const c_char) -> c_int>,
+        );
+        pub fn gpgrt_set_fixed_string_mapper(
+            f: Option<unsafe extern "C" fn(*const c_char) -> *const c_char>,
+        );
+        pub fn gpgrt_set_confdir(what: c_int, name: *const c_char);
+    }
+}
+
+pub mod b64 {
+    use core::ffi::{c_char, c_void};
+
+    use crate::types::{gpg_err_code_t, gpg_error_t, gpgrt_stream_t};
+
+    #[repr(C)]
+    pub struct _gpgrt_b64state {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_b64state_t = *mut _gpgrt_b64state;
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_b64enc_start(stream: gpgrt_stream_t, title: *const c_char)
+            -> gpgrt_b64state_t;
+        pub fn gpgrt_b64enc_write(
+            state: gpgrt_b64state_t,
+            buffer: *const c_void,
+            nbytes: usize,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_b64enc_finish(state: gpgrt_b64state_t) -> gpg_err_code_t;
+
+        pub fn gpgrt_b64dec_start(title: *const c_char) -> gpgrt_b64state_t;
+        pub fn gpgrt_b64dec_proc(
+            state: gpgrt_b64state_t,
+            buffer: *mut c_void,
+            length: usize,
+            r_nbytes: *mut usize,
+        ) -> gpg_error_t;
+        pub fn gpgrt_b64dec_finish(state: gpgrt_b64state_t) -> gpg_error_t;
+    }
+}
+
+#[cfg(gpg_error_1_48)]
+pub mod process {
+    use core::ffi::{c_char, c_int, c_uint, c_void};
+
+    use crate::types::{gpg_err_code_t, gpgrt_stream_t};
+
+    #[repr(C)]
+    pub struct gpgrt_process {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_process_t = *mut gpgrt_process;
+
+    #[repr(C)]
+    pub struct gpgrt_spawn_actions {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_spawn_actions_t = *mut gpgrt_spawn_actions;
+
+    pub const GPGRT_PROCESS_DETACHED: c_uint = 1 << 1;
+    pub const GPGRT_PROCESS_NO_CONSOLE: c_uint = 1 << 2;
+    pub const GPGRT_PROCESS_NO_EUID_CHECK: c_uint = 1 << 3;
+    pub const GPGRT_PROCESS_ALLOW_SET_FG: c_uint = 1 << 4;
+    pub const GPGRT_PROCESS_STDIN_PIPE: c_uint = 1 << 8;
+    pub const GPGRT_PROCESS_STDOUT_PIPE: c_uint = 1 << 9;
+    pub const GPGRT_PROCESS_STDERR_PIPE: c_uint = 1 << 10;
+    pub const GPGRT_PROCESS_STDINOUT_SOCKETPAIR: c_uint = 1 << 11;
+    pub const GPGRT_PROCESS_STDIN_KEEP: c_uint = 1 << 12;
+    pub const GPGRT_PROCESS_STDOUT_KEEP: c_uint = 1 << 13;
+    pub const GPGRT_PROCESS_STDERR_KEEP: c_uint = 1 << 14;
+    pub const GPGRT_PROCESS_STDFDS_SETTING: c_uint = GPGRT_PROCESS_STDIN_PIPE
+        | GPGRT_PROCESS_STDOUT_PIPE
+        | GPGRT_PROCESS_STDERR_PIPE
+        | GPGRT_PROCESS_STDINOUT_SOCKETPAIR
+        | GPGRT_PROCESS_STDIN_KEEP
+        | GPGRT_PROCESS_STDOUT_KEEP
+        | GPGRT_PROCESS_STDERR_KEEP;
+    pub const GPGRT_PROCESS_STREAM_NONBLOCK: c_uint = 1 << 16;
+
+    pub const GPGRT_PROCESS_NOP: c_uint = 0;
+    pub const GPGRT_PROCESS_GET_PROC_ID: c_uint = 1;
+    pub const GPGRT_PROCESS_GET_EXIT_ID: c_uint = 2;
+    pub const GPGRT_PROCESS_GET_PID: c_uint = 16;
+    pub const GPGRT_PROCESS_GET_WSTATUS: c_uint = 17;
+    pub const GPGRT_PROCESS_KILL: c_uint = 18;
+    pub const GPGRT_PROCESS_GET_P_HANDLE: c_uint = 32;
+    pub const GPGRT_PROCESS_GET_HANDLES: c_uint = 33;
+    pub const GPGRT_PROCESS_GET_EXIT_CODE: c_uint = 34;
+    pub const GPGRT_PROCESS_KILL_WITH_EC: c_uint = 35;
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_spawn_actions_new(r_act: *mut gpgrt_spawn_actions_t) -> gpg_err_code_t;
+        pub fn gpgrt_spawn_actions_release(act: gpgrt_spawn_actions_t);
+        #[cfg(windows)]
+        pub fn gpgrt_spawn_actions_set_envvars(act: gpgrt_spawn_actions_t, env: *mut c_char);
+        #[cfg(windows)]
+        pub fn gpgrt_spawn_actions_set_redirect(
+            act: gpgrt_spawn_actions_t,
+            h_in: *mut c_void,
+            h_out: *mut c_void,
+            h_err: *mut c_void,
+        );
+        #[cfg(windows)]
+        pub fn gpgrt_spawn_actions_set_inherit_handles(
+            act: gpgrt_spawn_actions_t,
+            handles: *mut *mut c_void,
+        );
+        #[cfg(not(windows))]
+        pub fn gpgrt_spawn_actions_set_environ(
+            act: gpgrt_spawn_actions_t,
+            environ: *mut *mut c_char,
+        );
+        #[cfg(not(windows))]
+        pub fn gpgrt_spawn_actions_set_atfork(
+            act: gpgrt_spawn_actions_t,
+            atfork: Option<unsafe extern "C" fn(*mut c_void)>,
+            arg: *mut c_void,
+        );
+        #[cfg(not(windows))]
+        pub fn gpgrt_spawn_actions_set_redirect(
+            act: gpgrt_spawn_actions_t,
+            fd_in: c_int,
+            fd_out: c_int,
+            fd_err: c_int,
+        );
+        #[cfg(not(windows))]
+        pub fn gpgrt_spawn_actions_set_inherit_fds(act: gpgrt_spawn_actions_t, fds: *const c_int);
+
+        pub fn gpgrt_process_spawn(
+            pgmname: *const c_char,
+            argv: *mut *const c_char,
+            flags: c_uint,
+            act: gpgrt_spawn_actions_t,
+            r_process: *mut gpgrt_process_t,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_process_terminate(process: gpgrt_process_t) -> gpg_err_code_t;
+        pub fn gpgrt_process_get_fds(
+            process: gpgrt_process_t,
+            flags: c_uint,
+            r_fd_in: *mut c_int,
+            r_fd_out: *mut c_int,
+            r_fd_err: *mut c_int,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_process_get_streams(
+            process: gpgrt_process_t,
+            flags: c_uint,
+            r_fp_in: *mut gpgrt_stream_t,
+            r_fp_out: *mut gpgrt_stream_t,
+            r_fp_err: *mut gpgrt_stream_t,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_process_ctl(process: gpgrt_process_t, request: c_uint, ...) -> gpg_err_code_t;
+        pub fn gpgrt_process_wait(process: gpgrt_process_t, hang: c_int) -> gpg_err_code_t;
+        pub fn gpgrt_process_release(process: gpgrt_process_t);
+        pub fn gpgrt_process_wait_list(
+            process_list: *mut gpgrt_process_t,
+            count: c_int,
+            hang: c_int,
+        );
+    }
+}
+
+// Only available on targets for which the size of `gpgrt_lock_t` is known.
+#[cfg(gpgrt_lock)]
+pub mod lock {
+    use core::ffi::c_long;
+
+    use crate::types::gpg_err_code_t;
+
+    // Set by the build script from libgpg-error's lock-obj-pub.*.h files.
+    #[cfg(gpgrt_lock_size = "24")]
+    const LOCK_PRIV_SIZE: usize = 24;
+    #[cfg(gpgrt_lock_size = "40")]
+    const LOCK_PRIV_SIZE: usize = 40;
+    #[cfg(gpgrt_lock_size = "48")]
+    const LOCK_PRIV_SIZE: usize = 48;
+    #[cfg(gpgrt_lock_size = "64")]
+    const LOCK_PRIV_SIZE: usize = 64;
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub union gpgrt_lock_u {
+        pub _priv: [u8; LOCK_PRIV_SIZE],
+        pub _x_align: c_long,
+        pub _xp_align: *mut c_long,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct gpgrt_lock_t {
+        pub _vers: c_long,
+        pub u: gpgrt_lock_u,
+    }
+
+    pub const GPGRT_LOCK_INITIALIZER: gpgrt_lock_t = gpgrt_lock_t {
+        _vers: 1,
+        u: gpgrt_lock_u {
+            _priv: [0; LOCK_PRIV_SIZE],
+        },
+    };
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_lock_init(lockhd: *mut gpgrt_lock_t) -> gpg_err_code_t;
+        pub fn gpgrt_lock_lock(lockhd: *mut gpgrt_lock_t) -> gpg_err_code_t;
+        pub fn gpgrt_lock_trylock(lockhd: *mut gpgrt_lock_t) -> gpg_err_code_t;
+        pub fn gpgrt_lock_unlock(lockhd: *mut gpgrt_lock_t) -> gpg_err_code_t;
+        pub fn gpgrt_lock_destroy(lockhd: *mut gpgrt_lock_t) -> gpg_err_code_t;
+
+        pub fn gpgrt_yield() -> gpg_err_code_t;
+    }
+}
+
+#[cfg(gpg_error_1_49)]
+pub mod nvc {
+    use core::ffi::{c_char, c_int, c_uint};
+
+    use crate::types::{gpg_err_code_t, gpgrt_stream_t};
+
+    #[repr(C)]
+    pub struct _gpgrt_name_value_container {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_nvc_t = *mut _gpgrt_name_value_container;
+
+    #[repr(C)]
+    pub struct _gpgrt_name_value_entry {
+        _priv: [u8; 0],
+    }
+    pub type gpgrt_nve_t = *mut _gpgrt_name_value_entry;
+
+    pub const GPGRT_NVC_WIPE: c_uint = 2;
+    pub const GPGRT_NVC_PRIVKEY: c_uint = 4;
+    pub const GPGRT_NVC_SECTION: c_uint = 8;
+    pub const GPGRT_NVC_MODIFIED: c_uint = 256;
+
+    #[cfg_attr(
+        all(windows, feature = "windows_raw_dylib"),
+        link(
+            name = "libgpg-error-0.dll",
+            kind = "raw-dylib",
+            modifiers = "+verbatim"
+        )
+    )]
+    extern "C" {
+        pub fn gpgrt_nvc_new(flags: c_uint) -> gpgrt_nvc_t;
+        pub fn gpgrt_nvc_release(cont: gpgrt_nvc_t);
+        pub fn gpgrt_nvc_get_flag(cont: gpgrt_nvc_t, flags: c_uint, clear: c_int) -> c_int;
+        pub fn gpgrt_nvc_add(
+            cont: gpgrt_nvc_t,
+            name: *const c_char,
+            value: *const c_char,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_nvc_set(
+            cont: gpgrt_nvc_t,
+            name: *const c_char,
+            value: *const c_char,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_nve_set(
+            cont: gpgrt_nvc_t,
+            e: gpgrt_nve_t,
+            value: *const c_char,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_nvc_delete(cont: gpgrt_nvc_t, entry: gpgrt_nve_t, name: *const c_char);
+        pub fn gpgrt_nvc_lookup(cont: gpgrt_nvc_t, name: *const c_char) -> gpgrt_nve_t;
+        pub fn gpgrt_nve_next(entry: gpgrt_nve_t, name: *const c_char) -> gpgrt_nve_t;
+        pub fn gpgrt_nvc_get_string(nvc: gpgrt_nvc_t, name: *const c_char) -> *const c_char;
+        pub fn gpgrt_nvc_get_bool(nvc: gpgrt_nvc_t, name: *const c_char) -> c_int;
+        pub fn gpgrt_nve_name(pe: gpgrt_nve_t) -> *const c_char;
+        pub fn gpgrt_nve_value(pe: gpgrt_nve_t) -> *const c_char;
+        pub fn gpgrt_nvc_parse(
+            r_nvc: *mut gpgrt_nvc_t,
+            r_errlineno: *mut c_int,
+            stream: gpgrt_stream_t,
+            flags: c_uint,
+        ) -> gpg_err_code_t;
+        pub fn gpgrt_nvc_write(cont: gpgrt_nvc_t, stream: gpgrt_stream_t) -> gpg_err_code_t;
+    }
 }
@@ -0,0 +1,652 @@
+//! Safe wrappers for libgpg-error's command line and option file parser.
+//!
+//! The parser accepts the same syntax as GnuPG, including `--options` files,
+//! the global configuration files and meta commands such as `[ignore]`.
+//! It requires libgpg-error 1.41 or later.
+use std::{
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    ops::{BitOr, BitOrAssign},
+    os::raw::{c_char, c_int, c_uint},
+    path::Path,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{estream::Stream, Error, Result};
+
+/// Flags controlling the behavior of a [`Parser`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ParseFlags(c_uint);
+
+impl ParseFlags {
+    /// Do not remove parsed options from the argument list.
+    pub const KEEP: Self = Self(ffi::ARGPARSE_FLAG_KEEP);
+    /// Parse all arguments, even after a non-option argument.
+    pub const ALL: Self = Self(ffi::ARGPARSE_FLAG_ALL);
+    /// Return non-option arguments interleaved with options.
+    pub const MIXED: Self = Self(ffi::ARGPARSE_FLAG_MIXED);
+    /// Do not stop at `--`.
+    pub const NOSTOP: Self = Self(ffi::ARGPARSE_FLAG_NOSTOP);
+    /// Do not skip the first argument (the program name).
+    pub const ARG0: Self = Self(ffi::ARGPARSE_FLAG_ARG0);
+    /// Allow long options with a single dash.
+    pub const ONEDASH: Self = Self(ffi::ARGPARSE_FLAG_ONEDASH);
+    /// Do not handle `--version` and `--help` internally.
+    pub const NOVERSION: Self = Self(ffi::ARGPARSE_FLAG_NOVERSION);
+    /// Do not include line numbers in diagnostics.
+    pub const NOLINENO: Self = Self(ffi::ARGPARSE_FLAG_NOLINENO);
+    /// Read the configuration file from the system configuration directory.
+    pub const SYS: Self = Self(ffi::ARGPARSE_FLAG_SYS);
+    /// Read the configuration file from the user configuration directory.
+    pub const USER: Self = Self(ffi::ARGPARSE_FLAG_USER);
+    /// Report the configuration files being read.
+    pub const VERBOSE: Self = Self(ffi::ARGPARSE_FLAG_VERBOSE);
+    /// Try versioned user configuration files first.
+    pub const USERVERS: Self = Self(ffi::ARGPARSE_FLAG_USERVERS);
+
+    /// Returns the raw flag bits.
+    #[inline]
+    pub const fn bits(&self) -> c_uint {
+        self.0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for ParseFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ParseFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The type of an option's argument.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ArgType {
+    None,
+    Int,
+    Long,
+    ULong,
+    String,
+}
+
+impl ArgType {
+    #[inline]
+    fn raw(self) -> c_uint {
+        match self {
+            Self::None => ffi::ARGPARSE_TYPE_NONE,
+            Self::Int => ffi::ARGPARSE_TYPE_INT,
+            Self::Long => ffi::ARGPARSE_TYPE_LONG,
+            Self::ULong => ffi::ARGPARSE_TYPE_ULONG,
+            Self::String => ffi::ARGPARSE_TYPE_STRING,
+        }
+    }
+}
+
+/// Builds an [`OptionTable`].
+///
+/// Options are identified by an integer id which is returned by the parser.
+/// Printable ASCII ids double as the short option character.
+#[derive(Debug, Clone, Default)]
+pub struct OptionTableBuilder {
+    entries: Vec<(c_int, Option<String>, c_uint, Option<String>)>,
+}
+
+impl OptionTableBuilder {
+    fn entry(mut self, id: c_int, long: Option<&str>, flags: c_uint, desc: Option<&str>) -> Self {
+        self.entries
+            .push((id, long.map(String::from), flags, desc.map(String::from)));
+        self
+    }
+
+    /// Adds an option that takes no argument.
+    #[inline]
+    pub fn flag(self, id: i32, long: &str, desc: &str) -> Self {
+        self.option(id, long, ArgType::None, desc)
+    }
+
+    /// Adds an option that takes an argument of type `ty`.
+    #[inline]
+    pub fn option(self, id: i32, long: &str, ty: ArgType, desc: &str) -> Self {
+        self.entry(id, Some(long), ty.raw(), Some(desc))
+    }
+
+    /// Adds an option whose argument of type `ty` may be omitted.
+    #[inline]
+    pub fn optional(self, id: i32, long: &str, ty: ArgType, desc: &str) -> Self {
+        self.entry(
+            id,
+            Some(long),
+            ty.raw() | ffi::ARGPARSE_OPT_OPTIONAL,
+            Some(desc),
+        )
+    }
+
+    /// Adds a command, which is given without leading dashes.
+    #[inline]
+    pub fn command(self, id: i32, long: &str, desc: &str) -> Self {
+        self.entry(
+            id,
+            Some(long),
+            ffi::ARGPARSE_TYPE_NONE | ffi::ARGPARSE_OPT_COMMAND,
+            Some(desc),
+        )
+    }
+
+    /// Adds an option that is accepted but never returned by the parser.
+    #[inline]
+    pub fn ignore(self, id: i32, long: &str) -> Self {
+        self.entry(id, Some(long), ffi::ARGPARSE_OPT_IGNORE, Some("@"))
+    }
+
+    /// Adds an option naming an additional option file to read
+    /// (e.g. `--options`).
+    #[inline]
+    pub fn conffile(self, id: i32, long: &str, desc: &str) -> Self {
+        self.entry(
+            id,
+            Some(long),
+            ffi::ARGPARSE_TYPE_STRING | ffi::ARGPARSE_OPT_CONFFILE,
+            Some(desc),
+        )
+    }
+
+    /// Adds an option that disables reading the default option file
+    /// (e.g. `--no-options`).
+    #[inline]
+    pub fn noconffile(self, id: i32, long: &str, desc: &str) -> Self {
+        self.entry(
+            id,
+            Some(long),
+            ffi::ARGPARSE_TYPE_NONE | ffi::ARGPARSE_OPT_CONFFILE,
+            Some(desc),
+        )
+    }
+
+    /// Adds a section header to the help output.
+    #[inline]
+    pub fn header(self, name: &str, desc: &str) -> Self {
+        self.entry(1, Some(name), ffi::ARGPARSE_OPT_HEADER, Some(desc))
+    }
+
+    /// Adds text that is printed as is in the help output.
+    #[inline]
+    pub fn verbatim(self, text: &str) -> Self {
+        self.entry(1, None, ffi::ARGPARSE_OPT_VERBATIM, Some(text))
+    }
+
+    /// Builds the option table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name or description contains a nul byte, or if
+    /// an option id is not positive.
+    pub fn build(self) -> Result<OptionTable> {
+        let mut strings = Vec::new();
+        let mut opts = Vec::with_capacity(self.entries.len() + 1);
+        let mut intern = |s: Option<String>| -> Result<*const c_char> {
+            match s {
+                Some(s) => {
+                    let s = CString::new(s)?;
+                    let ptr = s.as_ptr();
+                    strings.push(s);
+                    Ok(ptr)
+                }
+                None => Ok(ptr::null()),
+            }
+        };
+        for (id, long, flags, desc) in self.entries {
+            if id <= 0 {
+                return Err(Error::EINVAL);
+            }
+            opts.push(ffi::gpgrt_opt_t {
+                short_opt: id,
+                long_opt: intern(long)?,
+                flags,
+                description: intern(desc)?,
+            });
+        }
+        opts.push(ffi::gpgrt_opt_t {
+            short_opt: 0,
+            long_opt: ptr::null(),
+            flags: 0,
+            description: ptr::null(),
+        });
+        Ok(OptionTable {
+            opts,
+            _strings: strings,
+        })
+    }
+}
+
+/// A table of options understood by a [`Parser`].
+pub struct OptionTable {
+    opts: Vec<ffi::gpgrt_opt_t>,
+    _strings: Vec<CString>,
+}
+
+unsafe impl Send for OptionTable {}
+unsafe impl Sync for OptionTable {}
+
+impl OptionTable {
+    /// Returns a builder for an option table.
+    #[inline]
+    pub fn builder() -> OptionTableBuilder {
+        OptionTableBuilder::default()
+    }
+}
+
+/// The value of a parsed option.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Value {
+    None,
+    Int(i32),
+    Long(i64),
+    ULong(u64),
+    String(String),
+}
+
+/// An item returned by a [`Parser`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Parsed {
+    /// An option or command with the given id.
+    Opt { id: i32, value: Value },
+    /// A non-option argument, only returned with [`ParseFlags::MIXED`].
+    Arg(String),
+    /// An option file is being read, or all option files have been read and
+    /// the command line follows if `None`.
+    ConfFile(Option<String>),
+}
+
+/// Parses options from a command line or an option file.
+///
+/// Errors are returned in place of the offending option and parsing may
+/// continue afterwards. Use [`Parser::lineno`] to locate errors in option
+/// files.
+pub struct Parser<'a> {
+    opts: Vec<ffi::gpgrt_opt_t>,
+    state: ffi::gpgrt_argparse_t,
+    argc: c_int,
+    argv: *mut *mut c_char,
+    _ptrs: Vec<*mut c_char>,
+    _args: Vec<CString>,
+    stream: Option<&'a mut Stream>,
+    confname: Option<CString>,
+    done: bool,
+    _table: PhantomData<&'a OptionTable>,
+}
+
+unsafe impl Send for Parser<'_> {}
+
+impl<'a> Parser<'a> {
+    /// Creates a parser for the command line `args`, which includes the
+    /// program name unless [`ParseFlags::ARG0`] is given.
+    ///
+    /// Unless [`ParseFlags::NOVERSION`] is given, `--version` and `--help`
+    /// are handled by libgpg-error, which prints the requested text and
+    /// terminates the process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an argument contains a nul byte.
+    pub fn new<I>(table: &'a OptionTable, args: I, flags: ParseFlags) -> Result<Self>
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec<u8>>,
+    {
+        let args = args
+            .into_iter()
+            .map(CString::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::with_args(table, args, flags, None))
+    }
+
+    /// Creates a parser reading options from the option file `stream`.
+    pub fn from_stream(table: &'a OptionTable, stream: &'a mut Stream, flags: ParseFlags) -> Self {
+        Self::with_args(table, Vec::new(), flags, Some(stream))
+    }
+
+    fn with_args(
+        table: &'a OptionTable,
+        args: Vec<CString>,
+        flags: ParseFlags,
+        stream: Option<&'a mut Stream>,
+    ) -> Self {
+        let mut ptrs: Vec<_> = args.iter().map(|a| a.as_ptr() as *mut c_char).collect();
+        ptrs.push(ptr::null_mut());
+        Self {
+            opts: table.opts.clone(),
+            state: ffi::gpgrt_argparse_t {
+                argc: ptr::null_mut(),
+                argv: ptr::null_mut(),
+                flags: flags.bits(),
+                err: 0,
+                lineno: 0,
+                r_opt: 0,
+                r_type: 0,
+                r: ffi::gpgrt_argparse_r { ret_int: 0 },
+                internal: ptr::null_mut(),
+            },
+            argc: args.len() as c_int,
+            argv: ptrs.as_mut_ptr(),
+            _ptrs: ptrs,
+            _args: args,
+            stream,
+            confname: None,
+            done: false,
+            _table: PhantomData,
+        }
+    }
+
+    /// Reads the configuration file `name` from the directories selected with
+    /// [`ParseFlags::SYS`] and [`ParseFlags::USER`] before parsing the command
+    /// line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` contains a nul byte.
+    pub fn config_file(mut self, name: &str) -> Result<Self> {
+        self.confname = Some(CString::new(name)?);
+        Ok(self)
+    }
+
+    /// Returns the line number of the last item read from an option file.
+    #[inline]
+    pub fn lineno(&self) -> u32 {
+        self.state.lineno
+    }
+
+    /// Returns the arguments that have not been consumed by the parser.
+    pub fn remaining(&self) -> Vec<String> {
+        (0..self.argc.max(0) as usize)
+            .filter_map(|i| unsafe { (*self.argv.add(i)).as_ref() })
+            .map(|s| unsafe { CStr::from_ptr(s).to_string_lossy().into_owned() })
+            .collect()
+    }
+
+    unsafe fn string_value(&self) -> String {
+        self.state
+            .r
+            .ret_str
+            .as_ref()
+            .map(|s| CStr::from_ptr(s).to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    #[allow(clippy::useless_conversion)]
+    unsafe fn value(&self) -> Value {
+        let r = &self.state.r;
+        match self.state.r_type as c_uint & ffi::ARGPARSE_TYPE_MASK {
+            ffi::ARGPARSE_TYPE_INT => Value::Int(r.ret_int),
+            ffi::ARGPARSE_TYPE_LONG => Value::Long(r.ret_long.into()),
+            ffi::ARGPARSE_TYPE_ULONG => Value::ULong(r.ret_ulong.into()),
+            ffi::ARGPARSE_TYPE_STRING => Value::String(self.string_value()),
+            _ => Value::None,
+        }
+    }
+}
+
+impl Iterator for Parser<'_> {
+    type Item = Result<Parsed>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.state.argc = &mut self.argc;
+        self.state.argv = &mut self.argv;
+        self.state.err = 0;
+        let opt = unsafe {
+            match (&mut self.stream, &self.confname) {
+                (Some(stream), _) => {
+                    ffi::gpgrt_argparse(stream.as_raw(), &mut self.state, self.opts.as_mut_ptr())
+                }
+                (None, Some(name)) => {
+                    ffi::gpgrt_argparser(&mut self.state, self.opts.as_mut_ptr(), name.as_ptr())
+                }
+                (None, None) => {
+                    ffi::gpgrt_argparse(ptr::null_mut(), &mut self.state, self.opts.as_mut_ptr())
+                }
+            }
+        };
+        let item = unsafe {
+            match opt {
+                0 => {
+                    self.done = true;
+                    return None;
+                }
+                ffi::ARGPARSE_IS_ARG => Ok(Parsed::Arg(self.string_value())),
+                ffi::ARGPARSE_CONFFILE => Ok(Parsed::ConfFile(
+                    (self.state.r_type != 0).then(|| self.string_value()),
+                )),
+                id if id > 0 => Ok(Parsed::Opt {
+                    id,
+                    value: self.value(),
+                }),
+                err => Err(error_from_status(err)),
+            }
+        };
+        Some(item)
+    }
+}
+
+impl Drop for Parser<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_argparse(ptr::null_mut(), &mut self.state, ptr::null_mut());
+        }
+    }
+}
+
+fn error_from_status(status: c_int) -> Error {
+    match status {
+        ffi::ARGPARSE_INVALID_OPTION => Error::UNKNOWN_OPTION,
+        ffi::ARGPARSE_MISSING_ARG => Error::MISSING_VALUE,
+        ffi::ARGPARSE_KEYWORD_TOO_LONG => Error::LINE_TOO_LONG,
+        ffi::ARGPARSE_READ_ERROR => Error::EIO,
+        ffi::ARGPARSE_UNEXPECTED_ARG => Error::UNEXPECTED,
+        ffi::ARGPARSE_INVALID_COMMAND => Error::UNKNOWN_COMMAND,
+        ffi::ARGPARSE_AMBIGUOUS_OPTION | ffi::ARGPARSE_AMBIGUOUS_COMMAND => Error::AMBIGUOUS_NAME,
+        ffi::ARGPARSE_INVALID_ALIAS => Error::INV_NAME,
+        ffi::ARGPARSE_OUT_OF_CORE => Error::ENOMEM,
+        ffi::ARGPARSE_INVALID_ARG => Error::INV_VALUE,
+        ffi::ARGPARSE_PERMISSION_ERROR => Error::EPERM,
+        ffi::ARGPARSE_NO_CONFFILE => Error::ENOENT,
+        ffi::ARGPARSE_INVALID_META | ffi::ARGPARSE_UNKNOWN_META | ffi::ARGPARSE_UNEXPECTED_META => {
+            Error::SYNTAX
+        }
+        _ => Error::GENERAL,
+    }
+}
+
+/// Which configuration directory to set.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ConfDir {
+    User,
+    Sys,
+}
+
+/// Sets the directory searched for configuration files.
+///
+/// # Safety
+///
+/// libgpg-error does not synchronize its parser configuration. This must not
+/// be called while any other thread may be parsing options.
+pub unsafe fn set_confdir(which: ConfDir, path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref().to_str().ok_or(Error::EINVAL)?;
+    let path = CString::new(path)?;
+    let what = match which {
+        ConfDir::User => ffi::GPGRT_CONFDIR_USER,
+        ConfDir::Sys => ffi::GPGRT_CONFDIR_SYS,
+    };
+    ffi::gpgrt_set_confdir(what, path.as_ptr());
+    Ok(())
+}
+
+/// Strings used for usage and help output.
+#[derive(Debug, Clone, Default)]
+pub struct Usage {
+    /// The name of the program.
+    pub program: Option<String>,
+    /// The name of the package the program belongs to.
+    pub package: Option<String>,
+    /// The version of the program.
+    pub version: Option<String>,
+    /// The copyright line.
+    pub copyright: Option<String>,
+    /// The SPDX license identifier.
+    pub license: Option<String>,
+    /// Where to report bugs.
+    pub bug_reports: Option<String>,
+    /// The short usage line (e.g. `Usage: foo [options] (-h for help)`).
+    pub usage: Option<String>,
+    /// The syntax line and description printed by `--help`.
+    pub description: Option<String>,
+}
+
+const USAGE_LEVELS: [c_int; 8] = [11, 12, 13, 14, 9, 19, 40, 41];
+
+static USAGE: AtomicPtr<[Option<CString>; 8]> = AtomicPtr::new(ptr::null_mut());
+
+unsafe extern "C" fn strusage_cb(level: c_int) -> *const c_char {
+    let level = if level == 1 { 40 } else { level };
+    USAGE
+        .load(Ordering::Acquire)
+        .as_ref()
+        .and_then(|strings| {
+            let idx = USAGE_LEVELS.iter().position(|&l| l == level)?;
+            strings[idx].as_ref()
+        })
+        .map_or(ptr::null(), |s| s.as_ptr())
+}
+
+/// Sets the strings used for usage and help output.
+///
+/// The strings are never freed.
+///
+/// # Safety
+///
+/// See [`set_confdir`].
+pub unsafe fn set_usage(usage: Usage) -> Result<()> {
+    let convert = |s: Option<String>| s.map(CString::new).transpose();
+    let strings = Box::new([
+        convert(usage.program)?,
+        convert(usage.package)?,
+        convert(usage.version)?,
+        convert(usage.copyright)?,
+        convert(usage.license)?,
+        convert(usage.bug_reports)?,
+        convert(usage.usage)?,
+        convert(usage.description)?,
+    ]);
+    USAGE.store(Box::into_raw(strings), Ordering::Release);
+    ffi::gpgrt_set_strusage(Some(strusage_cb));
+    Ok(())
+}
+
+/// Returns the usage string for `level`, as understood by `gpgrt_strusage`.
+#[inline]
+pub fn strusage(level: i32) -> Option<&'static str> {
+    unsafe {
+        ffi::gpgrt_strusage(level)
+            .as_ref()
+            .and_then(|s| CStr::from_ptr(s).to_str().ok())
+    }
+}
+
+/// Prints the short usage text and terminates the process.
+pub fn usage() -> ! {
+    unsafe {
+        ffi::gpgrt_usage(1);
+    }
+    unreachable!("gpgrt_usage returned")
+}
+
+/// Prints the full help text and terminates the process.
+pub fn help() -> ! {
+    unsafe {
+        ffi::gpgrt_usage(2);
+    }
+    unreachable!("gpgrt_usage returned")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Seek, SeekFrom, Write};
+
+    use super::*;
+
+    const VERBOSE: i32 = b'v' as i32;
+    const OUTPUT: i32 = b'o' as i32;
+    const LEVEL: i32 = 300;
+
+    fn table() -> OptionTable {
+        OptionTable::builder()
+            .header("Options", "Options controlling the output")
+            .flag(VERBOSE, "verbose", "be verbose")
+            .option(OUTPUT, "output", ArgType::String, "|FILE|write to FILE")
+            .option(LEVEL, "level", ArgType::Int, "set the level")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_args() {
+        let table = table();
+        let args = ["prog", "-v", "--output", "out.txt", "--level=3", "file"];
+        let mut parser = Parser::new(&table, args, ParseFlags::NOVERSION).unwrap();
+        let items = parser.by_ref().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(
+            items,
+            [
+                Parsed::Opt {
+                    id: VERBOSE,
+                    value: Value::None
+                },
+                Parsed::Opt {
+                    id: OUTPUT,
+                    value: Value::String("out.txt".into())
+                },
+                Parsed::Opt {
+                    id: LEVEL,
+                    value: Value::Int(3)
+                },
+            ]
+        );
+        assert_eq!(parser.remaining(), ["file"]);
+
+        let mut parser = Parser::new(&table, ["prog", "--bogus"], ParseFlags::NOVERSION).unwrap();
+        assert_eq!(parser.next().unwrap(), Err(Error::UNKNOWN_OPTION));
+    }
+
+    #[test]
+    fn test_stream() {
+        let table = table();
+        let mut stream = Stream::memory(0).unwrap();
+        stream.write_all(b"# comment\nverbose\nlevel 7\n").unwrap();
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let parser = Parser::from_stream(&table, &mut stream, ParseFlags::NOVERSION);
+        let ids = parser.map(|p| p.unwrap()).collect::<Vec<_>>();
+        assert_eq!(
+            ids[1],
+            Parsed::Opt {
+                id: LEVEL,
+                value: Value::Int(7)
+            }
+        );
+    }
+}
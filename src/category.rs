@@ -0,0 +1,143 @@
+use core::ops::RangeInclusive;
+
+use crate::{Error, ErrorCode};
+
+macro_rules! error_ranges {
+    ($($name:ident = $lo:literal..=$hi:literal),* $(,)?) => {
+        /// The group of related error codes an error code belongs to.
+        #[non_exhaustive]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        pub enum ErrorCategory {
+            /// Codes not belonging to any of the other categories.
+            General,
+            /// Codes wrapping a system `errno` value.
+            System,
+            $(
+                #[doc = concat!("Codes ", stringify!($lo), " to ", stringify!($hi), ".")]
+                $name,
+            )*
+        }
+
+        impl ErrorCategory {
+            /// Returns the category of a raw error code.
+            pub const fn from_raw(code: ffi::gpg_err_code_t) -> Self {
+                let code = code & ffi::GPG_ERR_CODE_MASK;
+                if code & ffi::GPG_ERR_SYSTEM_ERROR != 0 {
+                    return Self::System;
+                }
+                $(if matches!(code, $lo..=$hi) {
+                    return Self::$name;
+                })*
+                Self::General
+            }
+
+            /// Returns the range of codes assigned to the category, if it
+            /// has one.
+            ///
+            /// Ranges include the codes reserved for future use by the
+            /// category.
+            pub const fn codes(&self) -> Option<RangeInclusive<ffi::gpg_err_code_t>> {
+                match self {
+                    Self::General | Self::System => None,
+                    $(Self::$name => Some($lo..=$hi),)*
+                }
+            }
+        }
+    };
+}
+
+include!("ranges.rs");
+
+impl ErrorCode {
+    /// Returns the category of the error code.
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        ErrorCategory::from_raw(self.raw())
+    }
+}
+
+impl Error {
+    /// Returns the category of the error's code.
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        ErrorCategory::from_raw(self.raw_code())
+    }
+
+    /// Returns `true` if the error wraps a system `errno` value.
+    #[inline]
+    pub const fn is_system(&self) -> bool {
+        matches!(self.category(), ErrorCategory::System)
+    }
+
+    /// Returns `true` if the error was produced by the Assuan IPC layer.
+    #[inline]
+    pub const fn is_assuan(&self) -> bool {
+        matches!(self.category(), ErrorCategory::Assuan)
+    }
+
+    /// Returns `true` if the error is a DNS error.
+    #[inline]
+    pub const fn is_dns(&self) -> bool {
+        matches!(self.category(), ErrorCategory::Dns)
+    }
+
+    /// Returns `true` if the error is a mapped LDAP error.
+    #[inline]
+    pub const fn is_ldap(&self) -> bool {
+        matches!(self.category(), ErrorCategory::Ldap)
+    }
+
+    /// Returns `true` if the error is a mapped SQLite error.
+    #[inline]
+    pub const fn is_sql(&self) -> bool {
+        matches!(self.category(), ErrorCategory::Sql)
+    }
+
+    /// Returns `true` if the error uses one of the codes reserved for
+    /// components outside of GnuPG (`Error::USER_1` to `Error::USER_16`).
+    #[inline]
+    pub const fn is_user_defined(&self) -> bool {
+        matches!(self.category(), ErrorCategory::User)
+    }
+
+    /// Returns `true` if the error indicates that an operation was canceled.
+    #[inline]
+    pub const fn is_canceled(&self) -> bool {
+        matches!(
+            self.raw_code(),
+            ffi::GPG_ERR_CANCELED | ffi::GPG_ERR_FULLY_CANCELED | ffi::GPG_ERR_ASS_CANCELED
+        )
+    }
+
+    /// Returns `true` if the error indicates the end of a file or of a list.
+    #[inline]
+    pub const fn is_eof(&self) -> bool {
+        matches!(self.raw_code(), ffi::GPG_ERR_EOF | ffi::GPG_ERR_EOF_GCRYPT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category() {
+        assert_eq!(Error::EPIPE.category(), ErrorCategory::System);
+        assert_eq!(Error::ASS_GENERAL.category(), ErrorCategory::Assuan);
+        assert_eq!(Error::new(299).category(), ErrorCategory::Assuan);
+        assert_eq!(Error::ENGINE_TOO_OLD.category(), ErrorCategory::General);
+        assert_eq!(Error::DNS_TIMEOUT.category(), ErrorCategory::Dns);
+        assert_eq!(Error::LDAP_PROX_AUTH_DENIED.category(), ErrorCategory::Ldap);
+        assert_eq!(Error::USER_16.category(), ErrorCategory::User);
+        assert_eq!(Error::SQL_DONE.category(), ErrorCategory::Sql);
+        assert_eq!(Error::USER_ID_EXISTS.category(), ErrorCategory::General);
+        assert_eq!(ErrorCode::SQL_OK.category(), ErrorCategory::Sql);
+        assert_eq!(ErrorCategory::Ldap.codes(), Some(721..=950));
+        assert_eq!(ErrorCategory::System.codes(), None);
+
+        let err = Error::from_source(Error::SOURCE_GPGME, ErrorCode::ASS_CANCELED);
+        assert!(err.is_assuan() && err.is_canceled());
+        assert!(Error::EOF.is_eof() && !Error::EOF.is_system());
+        assert!(Error::EAGAIN.is_system());
+    }
+}
@@ -0,0 +1,143 @@
+use core::{fmt, str::FromStr};
+
+use crate::Error;
+
+const PREFIX: &str = "GPG_ERR_";
+
+macro_rules! error_codes {
+    ($($name:ident = $value:path),* $(,)?) => {
+        /// An error code without an associated error source.
+        ///
+        /// Codes that are not known to this version of the crate are
+        /// represented by the `Unknown` variant, which can only be created
+        /// by [`ErrorCode::from_raw`].
+        #[non_exhaustive]
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        pub enum ErrorCode {
+            $($name,)*
+            Unknown(UnknownCode),
+        }
+
+        impl ErrorCode {
+            /// Creates a new error code from a raw code value.
+            ///
+            /// Any bits outside of the error code mask are ignored.
+            #[inline]
+            pub const fn from_raw(code: ffi::gpg_err_code_t) -> Self {
+                match code & ffi::GPG_ERR_CODE_MASK {
+                    $($value => Self::$name,)*
+                    x => Self::Unknown(UnknownCode(x)),
+                }
+            }
+
+            /// Returns the raw value of the error code.
+            #[inline]
+            pub const fn raw(&self) -> ffi::gpg_err_code_t {
+                match *self {
+                    $(Self::$name => $value,)*
+                    Self::Unknown(x) => x.0,
+                }
+            }
+
+            /// Returns the symbolic name of the error code (e.g. `GPG_ERR_NO_PUBKEY`),
+            /// if it is known.
+            #[inline]
+            pub const fn name(&self) -> Option<&'static str> {
+                match *self {
+                    $(Self::$name => Some(concat!("GPG_ERR_", stringify!($name))),)*
+                    Self::Unknown(_) => None,
+                }
+            }
+
+            const KNOWN: &'static [(Self, &'static str)] = &[
+                $((Self::$name, stringify!($name)),)*
+            ];
+        }
+    };
+}
+
+include!("codes.rs");
+
+/// The value of an [`ErrorCode`] without a symbolic name.
+///
+/// Values are guaranteed to fit in `GPG_ERR_CODE_MASK` and to differ from
+/// the values of all named codes.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct UnknownCode(ffi::gpg_err_code_t);
+
+impl UnknownCode {
+    /// Returns the raw code value.
+    #[inline]
+    pub const fn raw(&self) -> ffi::gpg_err_code_t {
+        self.0
+    }
+}
+
+impl fmt::Debug for UnknownCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl ErrorCode {
+    /// Looks up an error code by its symbolic name.
+    ///
+    /// Both the full name (`GPG_ERR_NO_PUBKEY`) and the name without
+    /// the prefix (`NO_PUBKEY`, `EPIPE`) are accepted, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let short = match name.get(..PREFIX.len()) {
+            Some(p) if p.eq_ignore_ascii_case(PREFIX) => &name[PREFIX.len()..],
+            _ => name,
+        };
+        Self::KNOWN
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(short))
+            .map(|&(c, _)| c)
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = Error;
+
+    /// Parses an error code from its symbolic name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_NAME` if the name is not recognized.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_name(s).ok_or(Error::INV_NAME)
+    }
+}
+
+impl TryFrom<ffi::gpg_err_code_t> for ErrorCode {
+    type Error = Error;
+
+    /// Converts a raw code value into an `ErrorCode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::EINVAL` if the value does not fit in the error code mask.
+    #[inline]
+    fn try_from(code: ffi::gpg_err_code_t) -> Result<Self, Error> {
+        if code > ffi::GPG_ERR_CODE_MASK {
+            return Err(Error::EINVAL);
+        }
+        Ok(Self::from_raw(code))
+    }
+}
+
+impl From<ErrorCode> for ffi::gpg_err_code_t {
+    #[inline]
+    fn from(code: ErrorCode) -> Self {
+        code.raw()
+    }
+}
+
+impl From<ErrorCode> for Error {
+    #[inline]
+    fn from(code: ErrorCode) -> Self {
+        Self::from_code(code)
+    }
+}
@@ -0,0 +1,584 @@
+error_codes! {
+    NO_ERROR = ffi::GPG_ERR_NO_ERROR,
+    GENERAL = ffi::GPG_ERR_GENERAL,
+    UNKNOWN_PACKET = ffi::GPG_ERR_UNKNOWN_PACKET,
+    UNKNOWN_VERSION = ffi::GPG_ERR_UNKNOWN_VERSION,
+    PUBKEY_ALGO = ffi::GPG_ERR_PUBKEY_ALGO,
+    DIGEST_ALGO = ffi::GPG_ERR_DIGEST_ALGO,
+    BAD_PUBKEY = ffi::GPG_ERR_BAD_PUBKEY,
+    BAD_SECKEY = ffi::GPG_ERR_BAD_SECKEY,
+    BAD_SIGNATURE = ffi::GPG_ERR_BAD_SIGNATURE,
+    NO_PUBKEY = ffi::GPG_ERR_NO_PUBKEY,
+    CHECKSUM = ffi::GPG_ERR_CHECKSUM,
+    BAD_PASSPHRASE = ffi::GPG_ERR_BAD_PASSPHRASE,
+    CIPHER_ALGO = ffi::GPG_ERR_CIPHER_ALGO,
+    KEYRING_OPEN = ffi::GPG_ERR_KEYRING_OPEN,
+    INV_PACKET = ffi::GPG_ERR_INV_PACKET,
+    INV_ARMOR = ffi::GPG_ERR_INV_ARMOR,
+    NO_USER_ID = ffi::GPG_ERR_NO_USER_ID,
+    NO_SECKEY = ffi::GPG_ERR_NO_SECKEY,
+    WRONG_SECKEY = ffi::GPG_ERR_WRONG_SECKEY,
+    BAD_KEY = ffi::GPG_ERR_BAD_KEY,
+    COMPR_ALGO = ffi::GPG_ERR_COMPR_ALGO,
+    NO_PRIME = ffi::GPG_ERR_NO_PRIME,
+    NO_ENCODING_METHOD = ffi::GPG_ERR_NO_ENCODING_METHOD,
+    NO_ENCRYPTION_SCHEME = ffi::GPG_ERR_NO_ENCRYPTION_SCHEME,
+    NO_SIGNATURE_SCHEME = ffi::GPG_ERR_NO_SIGNATURE_SCHEME,
+    INV_ATTR = ffi::GPG_ERR_INV_ATTR,
+    NO_VALUE = ffi::GPG_ERR_NO_VALUE,
+    NOT_FOUND = ffi::GPG_ERR_NOT_FOUND,
+    VALUE_NOT_FOUND = ffi::GPG_ERR_VALUE_NOT_FOUND,
+    SYNTAX = ffi::GPG_ERR_SYNTAX,
+    BAD_MPI = ffi::GPG_ERR_BAD_MPI,
+    INV_PASSPHRASE = ffi::GPG_ERR_INV_PASSPHRASE,
+    SIG_CLASS = ffi::GPG_ERR_SIG_CLASS,
+    RESOURCE_LIMIT = ffi::GPG_ERR_RESOURCE_LIMIT,
+    INV_KEYRING = ffi::GPG_ERR_INV_KEYRING,
+    TRUSTDB = ffi::GPG_ERR_TRUSTDB,
+    BAD_CERT = ffi::GPG_ERR_BAD_CERT,
+    INV_USER_ID = ffi::GPG_ERR_INV_USER_ID,
+    UNEXPECTED = ffi::GPG_ERR_UNEXPECTED,
+    TIME_CONFLICT = ffi::GPG_ERR_TIME_CONFLICT,
+    KEYSERVER = ffi::GPG_ERR_KEYSERVER,
+    WRONG_PUBKEY_ALGO = ffi::GPG_ERR_WRONG_PUBKEY_ALGO,
+    TRIBUTE_TO_D_A = ffi::GPG_ERR_TRIBUTE_TO_D_A,
+    WEAK_KEY = ffi::GPG_ERR_WEAK_KEY,
+    INV_KEYLEN = ffi::GPG_ERR_INV_KEYLEN,
+    INV_ARG = ffi::GPG_ERR_INV_ARG,
+    BAD_URI = ffi::GPG_ERR_BAD_URI,
+    INV_URI = ffi::GPG_ERR_INV_URI,
+    NETWORK = ffi::GPG_ERR_NETWORK,
+    UNKNOWN_HOST = ffi::GPG_ERR_UNKNOWN_HOST,
+    SELFTEST_FAILED = ffi::GPG_ERR_SELFTEST_FAILED,
+    NOT_ENCRYPTED = ffi::GPG_ERR_NOT_ENCRYPTED,
+    NOT_PROCESSED = ffi::GPG_ERR_NOT_PROCESSED,
+    UNUSABLE_PUBKEY = ffi::GPG_ERR_UNUSABLE_PUBKEY,
+    UNUSABLE_SECKEY = ffi::GPG_ERR_UNUSABLE_SECKEY,
+    INV_VALUE = ffi::GPG_ERR_INV_VALUE,
+    BAD_CERT_CHAIN = ffi::GPG_ERR_BAD_CERT_CHAIN,
+    MISSING_CERT = ffi::GPG_ERR_MISSING_CERT,
+    NO_DATA = ffi::GPG_ERR_NO_DATA,
+    BUG = ffi::GPG_ERR_BUG,
+    NOT_SUPPORTED = ffi::GPG_ERR_NOT_SUPPORTED,
+    INV_OP = ffi::GPG_ERR_INV_OP,
+    TIMEOUT = ffi::GPG_ERR_TIMEOUT,
+    INTERNAL = ffi::GPG_ERR_INTERNAL,
+    EOF_GCRYPT = ffi::GPG_ERR_EOF_GCRYPT,
+    INV_OBJ = ffi::GPG_ERR_INV_OBJ,
+    TOO_SHORT = ffi::GPG_ERR_TOO_SHORT,
+    TOO_LARGE = ffi::GPG_ERR_TOO_LARGE,
+    NO_OBJ = ffi::GPG_ERR_NO_OBJ,
+    NOT_IMPLEMENTED = ffi::GPG_ERR_NOT_IMPLEMENTED,
+    CONFLICT = ffi::GPG_ERR_CONFLICT,
+    INV_CIPHER_MODE = ffi::GPG_ERR_INV_CIPHER_MODE,
+    INV_FLAG = ffi::GPG_ERR_INV_FLAG,
+    INV_HANDLE = ffi::GPG_ERR_INV_HANDLE,
+    TRUNCATED = ffi::GPG_ERR_TRUNCATED,
+    INCOMPLETE_LINE = ffi::GPG_ERR_INCOMPLETE_LINE,
+    INV_RESPONSE = ffi::GPG_ERR_INV_RESPONSE,
+    NO_AGENT = ffi::GPG_ERR_NO_AGENT,
+    AGENT = ffi::GPG_ERR_AGENT,
+    INV_DATA = ffi::GPG_ERR_INV_DATA,
+    ASSUAN_SERVER_FAULT = ffi::GPG_ERR_ASSUAN_SERVER_FAULT,
+    ASSUAN = ffi::GPG_ERR_ASSUAN,
+    INV_SESSION_KEY = ffi::GPG_ERR_INV_SESSION_KEY,
+    INV_SEXP = ffi::GPG_ERR_INV_SEXP,
+    UNSUPPORTED_ALGORITHM = ffi::GPG_ERR_UNSUPPORTED_ALGORITHM,
+    NO_PIN_ENTRY = ffi::GPG_ERR_NO_PIN_ENTRY,
+    PIN_ENTRY = ffi::GPG_ERR_PIN_ENTRY,
+    BAD_PIN = ffi::GPG_ERR_BAD_PIN,
+    INV_NAME = ffi::GPG_ERR_INV_NAME,
+    BAD_DATA = ffi::GPG_ERR_BAD_DATA,
+    INV_PARAMETER = ffi::GPG_ERR_INV_PARAMETER,
+    WRONG_CARD = ffi::GPG_ERR_WRONG_CARD,
+    NO_DIRMNGR = ffi::GPG_ERR_NO_DIRMNGR,
+    DIRMNGR = ffi::GPG_ERR_DIRMNGR,
+    CERT_REVOKED = ffi::GPG_ERR_CERT_REVOKED,
+    NO_CRL_KNOWN = ffi::GPG_ERR_NO_CRL_KNOWN,
+    CRL_TOO_OLD = ffi::GPG_ERR_CRL_TOO_OLD,
+    LINE_TOO_LONG = ffi::GPG_ERR_LINE_TOO_LONG,
+    NOT_TRUSTED = ffi::GPG_ERR_NOT_TRUSTED,
+    CANCELED = ffi::GPG_ERR_CANCELED,
+    BAD_CA_CERT = ffi::GPG_ERR_BAD_CA_CERT,
+    CERT_EXPIRED = ffi::GPG_ERR_CERT_EXPIRED,
+    CERT_TOO_YOUNG = ffi::GPG_ERR_CERT_TOO_YOUNG,
+    UNSUPPORTED_CERT = ffi::GPG_ERR_UNSUPPORTED_CERT,
+    UNKNOWN_SEXP = ffi::GPG_ERR_UNKNOWN_SEXP,
+    UNSUPPORTED_PROTECTION = ffi::GPG_ERR_UNSUPPORTED_PROTECTION,
+    CORRUPTED_PROTECTION = ffi::GPG_ERR_CORRUPTED_PROTECTION,
+    AMBIGUOUS_NAME = ffi::GPG_ERR_AMBIGUOUS_NAME,
+    CARD = ffi::GPG_ERR_CARD,
+    CARD_RESET = ffi::GPG_ERR_CARD_RESET,
+    CARD_REMOVED = ffi::GPG_ERR_CARD_REMOVED,
+    INV_CARD = ffi::GPG_ERR_INV_CARD,
+    CARD_NOT_PRESENT = ffi::GPG_ERR_CARD_NOT_PRESENT,
+    NO_PKCS15_APP = ffi::GPG_ERR_NO_PKCS15_APP,
+    NOT_CONFIRMED = ffi::GPG_ERR_NOT_CONFIRMED,
+    CONFIGURATION = ffi::GPG_ERR_CONFIGURATION,
+    NO_POLICY_MATCH = ffi::GPG_ERR_NO_POLICY_MATCH,
+    INV_INDEX = ffi::GPG_ERR_INV_INDEX,
+    INV_ID = ffi::GPG_ERR_INV_ID,
+    NO_SCDAEMON = ffi::GPG_ERR_NO_SCDAEMON,
+    SCDAEMON = ffi::GPG_ERR_SCDAEMON,
+    UNSUPPORTED_PROTOCOL = ffi::GPG_ERR_UNSUPPORTED_PROTOCOL,
+    BAD_PIN_METHOD = ffi::GPG_ERR_BAD_PIN_METHOD,
+    CARD_NOT_INITIALIZED = ffi::GPG_ERR_CARD_NOT_INITIALIZED,
+    UNSUPPORTED_OPERATION = ffi::GPG_ERR_UNSUPPORTED_OPERATION,
+    WRONG_KEY_USAGE = ffi::GPG_ERR_WRONG_KEY_USAGE,
+    NOTHING_FOUND = ffi::GPG_ERR_NOTHING_FOUND,
+    WRONG_BLOB_TYPE = ffi::GPG_ERR_WRONG_BLOB_TYPE,
+    MISSING_VALUE = ffi::GPG_ERR_MISSING_VALUE,
+    HARDWARE = ffi::GPG_ERR_HARDWARE,
+    PIN_BLOCKED = ffi::GPG_ERR_PIN_BLOCKED,
+    USE_CONDITIONS = ffi::GPG_ERR_USE_CONDITIONS,
+    PIN_NOT_SYNCED = ffi::GPG_ERR_PIN_NOT_SYNCED,
+    INV_CRL = ffi::GPG_ERR_INV_CRL,
+    BAD_BER = ffi::GPG_ERR_BAD_BER,
+    INV_BER = ffi::GPG_ERR_INV_BER,
+    ELEMENT_NOT_FOUND = ffi::GPG_ERR_ELEMENT_NOT_FOUND,
+    IDENTIFIER_NOT_FOUND = ffi::GPG_ERR_IDENTIFIER_NOT_FOUND,
+    INV_TAG = ffi::GPG_ERR_INV_TAG,
+    INV_LENGTH = ffi::GPG_ERR_INV_LENGTH,
+    INV_KEYINFO = ffi::GPG_ERR_INV_KEYINFO,
+    UNEXPECTED_TAG = ffi::GPG_ERR_UNEXPECTED_TAG,
+    NOT_DER_ENCODED = ffi::GPG_ERR_NOT_DER_ENCODED,
+    NO_CMS_OBJ = ffi::GPG_ERR_NO_CMS_OBJ,
+    INV_CMS_OBJ = ffi::GPG_ERR_INV_CMS_OBJ,
+    UNKNOWN_CMS_OBJ = ffi::GPG_ERR_UNKNOWN_CMS_OBJ,
+    UNSUPPORTED_CMS_OBJ = ffi::GPG_ERR_UNSUPPORTED_CMS_OBJ,
+    UNSUPPORTED_ENCODING = ffi::GPG_ERR_UNSUPPORTED_ENCODING,
+    UNSUPPORTED_CMS_VERSION = ffi::GPG_ERR_UNSUPPORTED_CMS_VERSION,
+    UNKNOWN_ALGORITHM = ffi::GPG_ERR_UNKNOWN_ALGORITHM,
+    INV_ENGINE = ffi::GPG_ERR_INV_ENGINE,
+    PUBKEY_NOT_TRUSTED = ffi::GPG_ERR_PUBKEY_NOT_TRUSTED,
+    DECRYPT_FAILED = ffi::GPG_ERR_DECRYPT_FAILED,
+    KEY_EXPIRED = ffi::GPG_ERR_KEY_EXPIRED,
+    SIG_EXPIRED = ffi::GPG_ERR_SIG_EXPIRED,
+    ENCODING_PROBLEM = ffi::GPG_ERR_ENCODING_PROBLEM,
+    INV_STATE = ffi::GPG_ERR_INV_STATE,
+    DUP_VALUE = ffi::GPG_ERR_DUP_VALUE,
+    MISSING_ACTION = ffi::GPG_ERR_MISSING_ACTION,
+    MODULE_NOT_FOUND = ffi::GPG_ERR_MODULE_NOT_FOUND,
+    INV_OID_STRING = ffi::GPG_ERR_INV_OID_STRING,
+    INV_TIME = ffi::GPG_ERR_INV_TIME,
+    INV_CRL_OBJ = ffi::GPG_ERR_INV_CRL_OBJ,
+    UNSUPPORTED_CRL_VERSION = ffi::GPG_ERR_UNSUPPORTED_CRL_VERSION,
+    INV_CERT_OBJ = ffi::GPG_ERR_INV_CERT_OBJ,
+    UNKNOWN_NAME = ffi::GPG_ERR_UNKNOWN_NAME,
+    LOCALE_PROBLEM = ffi::GPG_ERR_LOCALE_PROBLEM,
+    NOT_LOCKED = ffi::GPG_ERR_NOT_LOCKED,
+    PROTOCOL_VIOLATION = ffi::GPG_ERR_PROTOCOL_VIOLATION,
+    INV_MAC = ffi::GPG_ERR_INV_MAC,
+    INV_REQUEST = ffi::GPG_ERR_INV_REQUEST,
+    UNKNOWN_EXTN = ffi::GPG_ERR_UNKNOWN_EXTN,
+    UNKNOWN_CRIT_EXTN = ffi::GPG_ERR_UNKNOWN_CRIT_EXTN,
+    LOCKED = ffi::GPG_ERR_LOCKED,
+    UNKNOWN_OPTION = ffi::GPG_ERR_UNKNOWN_OPTION,
+    UNKNOWN_COMMAND = ffi::GPG_ERR_UNKNOWN_COMMAND,
+    NOT_OPERATIONAL = ffi::GPG_ERR_NOT_OPERATIONAL,
+    NO_PASSPHRASE = ffi::GPG_ERR_NO_PASSPHRASE,
+    NO_PIN = ffi::GPG_ERR_NO_PIN,
+    NOT_ENABLED = ffi::GPG_ERR_NOT_ENABLED,
+    NO_ENGINE = ffi::GPG_ERR_NO_ENGINE,
+    MISSING_KEY = ffi::GPG_ERR_MISSING_KEY,
+    TOO_MANY = ffi::GPG_ERR_TOO_MANY,
+    LIMIT_REACHED = ffi::GPG_ERR_LIMIT_REACHED,
+    NOT_INITIALIZED = ffi::GPG_ERR_NOT_INITIALIZED,
+    MISSING_ISSUER_CERT = ffi::GPG_ERR_MISSING_ISSUER_CERT,
+    NO_KEYSERVER = ffi::GPG_ERR_NO_KEYSERVER,
+    INV_CURVE = ffi::GPG_ERR_INV_CURVE,
+    UNKNOWN_CURVE = ffi::GPG_ERR_UNKNOWN_CURVE,
+    DUP_KEY = ffi::GPG_ERR_DUP_KEY,
+    AMBIGUOUS = ffi::GPG_ERR_AMBIGUOUS,
+    NO_CRYPT_CTX = ffi::GPG_ERR_NO_CRYPT_CTX,
+    WRONG_CRYPT_CTX = ffi::GPG_ERR_WRONG_CRYPT_CTX,
+    BAD_CRYPT_CTX = ffi::GPG_ERR_BAD_CRYPT_CTX,
+    CRYPT_CTX_CONFLICT = ffi::GPG_ERR_CRYPT_CTX_CONFLICT,
+    BROKEN_PUBKEY = ffi::GPG_ERR_BROKEN_PUBKEY,
+    BROKEN_SECKEY = ffi::GPG_ERR_BROKEN_SECKEY,
+    MAC_ALGO = ffi::GPG_ERR_MAC_ALGO,
+    FULLY_CANCELED = ffi::GPG_ERR_FULLY_CANCELED,
+    UNFINISHED = ffi::GPG_ERR_UNFINISHED,
+    BUFFER_TOO_SHORT = ffi::GPG_ERR_BUFFER_TOO_SHORT,
+    SEXP_INV_LEN_SPEC = ffi::GPG_ERR_SEXP_INV_LEN_SPEC,
+    SEXP_STRING_TOO_LONG = ffi::GPG_ERR_SEXP_STRING_TOO_LONG,
+    SEXP_UNMATCHED_PAREN = ffi::GPG_ERR_SEXP_UNMATCHED_PAREN,
+    SEXP_NOT_CANONICAL = ffi::GPG_ERR_SEXP_NOT_CANONICAL,
+    SEXP_BAD_CHARACTER = ffi::GPG_ERR_SEXP_BAD_CHARACTER,
+    SEXP_BAD_QUOTATION = ffi::GPG_ERR_SEXP_BAD_QUOTATION,
+    SEXP_ZERO_PREFIX = ffi::GPG_ERR_SEXP_ZERO_PREFIX,
+    SEXP_NESTED_DH = ffi::GPG_ERR_SEXP_NESTED_DH,
+    SEXP_UNMATCHED_DH = ffi::GPG_ERR_SEXP_UNMATCHED_DH,
+    SEXP_UNEXPECTED_PUNC = ffi::GPG_ERR_SEXP_UNEXPECTED_PUNC,
+    SEXP_BAD_HEX_CHAR = ffi::GPG_ERR_SEXP_BAD_HEX_CHAR,
+    SEXP_ODD_HEX_NUMBERS = ffi::GPG_ERR_SEXP_ODD_HEX_NUMBERS,
+    SEXP_BAD_OCT_CHAR = ffi::GPG_ERR_SEXP_BAD_OCT_CHAR,
+    SUBKEYS_EXP_OR_REV = ffi::GPG_ERR_SUBKEYS_EXP_OR_REV,
+    DB_CORRUPTED = ffi::GPG_ERR_DB_CORRUPTED,
+    SERVER_FAILED = ffi::GPG_ERR_SERVER_FAILED,
+    NO_NAME = ffi::GPG_ERR_NO_NAME,
+    NO_KEY = ffi::GPG_ERR_NO_KEY,
+    LEGACY_KEY = ffi::GPG_ERR_LEGACY_KEY,
+    REQUEST_TOO_SHORT = ffi::GPG_ERR_REQUEST_TOO_SHORT,
+    REQUEST_TOO_LONG = ffi::GPG_ERR_REQUEST_TOO_LONG,
+    OBJ_TERM_STATE = ffi::GPG_ERR_OBJ_TERM_STATE,
+    NO_CERT_CHAIN = ffi::GPG_ERR_NO_CERT_CHAIN,
+    CERT_TOO_LARGE = ffi::GPG_ERR_CERT_TOO_LARGE,
+    INV_RECORD = ffi::GPG_ERR_INV_RECORD,
+    BAD_MAC = ffi::GPG_ERR_BAD_MAC,
+    UNEXPECTED_MSG = ffi::GPG_ERR_UNEXPECTED_MSG,
+    COMPR_FAILED = ffi::GPG_ERR_COMPR_FAILED,
+    WOULD_WRAP = ffi::GPG_ERR_WOULD_WRAP,
+    FATAL_ALERT = ffi::GPG_ERR_FATAL_ALERT,
+    NO_CIPHER = ffi::GPG_ERR_NO_CIPHER,
+    MISSING_CLIENT_CERT = ffi::GPG_ERR_MISSING_CLIENT_CERT,
+    CLOSE_NOTIFY = ffi::GPG_ERR_CLOSE_NOTIFY,
+    TICKET_EXPIRED = ffi::GPG_ERR_TICKET_EXPIRED,
+    BAD_TICKET = ffi::GPG_ERR_BAD_TICKET,
+    UNKNOWN_IDENTITY = ffi::GPG_ERR_UNKNOWN_IDENTITY,
+    BAD_HS_CERT = ffi::GPG_ERR_BAD_HS_CERT,
+    BAD_HS_CERT_REQ = ffi::GPG_ERR_BAD_HS_CERT_REQ,
+    BAD_HS_CERT_VER = ffi::GPG_ERR_BAD_HS_CERT_VER,
+    BAD_HS_CHANGE_CIPHER = ffi::GPG_ERR_BAD_HS_CHANGE_CIPHER,
+    BAD_HS_CLIENT_HELLO = ffi::GPG_ERR_BAD_HS_CLIENT_HELLO,
+    BAD_HS_SERVER_HELLO = ffi::GPG_ERR_BAD_HS_SERVER_HELLO,
+    BAD_HS_SERVER_HELLO_DONE = ffi::GPG_ERR_BAD_HS_SERVER_HELLO_DONE,
+    BAD_HS_FINISHED = ffi::GPG_ERR_BAD_HS_FINISHED,
+    BAD_HS_SERVER_KEX = ffi::GPG_ERR_BAD_HS_SERVER_KEX,
+    BAD_HS_CLIENT_KEX = ffi::GPG_ERR_BAD_HS_CLIENT_KEX,
+    BOGUS_STRING = ffi::GPG_ERR_BOGUS_STRING,
+    FORBIDDEN = ffi::GPG_ERR_FORBIDDEN,
+    KEY_DISABLED = ffi::GPG_ERR_KEY_DISABLED,
+    KEY_ON_CARD = ffi::GPG_ERR_KEY_ON_CARD,
+    INV_LOCK_OBJ = ffi::GPG_ERR_INV_LOCK_OBJ,
+    TRUE = ffi::GPG_ERR_TRUE,
+    FALSE = ffi::GPG_ERR_FALSE,
+    ASS_GENERAL = ffi::GPG_ERR_ASS_GENERAL,
+    ASS_ACCEPT_FAILED = ffi::GPG_ERR_ASS_ACCEPT_FAILED,
+    ASS_CONNECT_FAILED = ffi::GPG_ERR_ASS_CONNECT_FAILED,
+    ASS_INV_RESPONSE = ffi::GPG_ERR_ASS_INV_RESPONSE,
+    ASS_INV_VALUE = ffi::GPG_ERR_ASS_INV_VALUE,
+    ASS_INCOMPLETE_LINE = ffi::GPG_ERR_ASS_INCOMPLETE_LINE,
+    ASS_LINE_TOO_LONG = ffi::GPG_ERR_ASS_LINE_TOO_LONG,
+    ASS_NESTED_COMMANDS = ffi::GPG_ERR_ASS_NESTED_COMMANDS,
+    ASS_NO_DATA_CB = ffi::GPG_ERR_ASS_NO_DATA_CB,
+    ASS_NO_INQUIRE_CB = ffi::GPG_ERR_ASS_NO_INQUIRE_CB,
+    ASS_NOT_A_SERVER = ffi::GPG_ERR_ASS_NOT_A_SERVER,
+    ASS_NOT_A_CLIENT = ffi::GPG_ERR_ASS_NOT_A_CLIENT,
+    ASS_SERVER_START = ffi::GPG_ERR_ASS_SERVER_START,
+    ASS_READ_ERROR = ffi::GPG_ERR_ASS_READ_ERROR,
+    ASS_WRITE_ERROR = ffi::GPG_ERR_ASS_WRITE_ERROR,
+    ASS_TOO_MUCH_DATA = ffi::GPG_ERR_ASS_TOO_MUCH_DATA,
+    ASS_UNEXPECTED_CMD = ffi::GPG_ERR_ASS_UNEXPECTED_CMD,
+    ASS_UNKNOWN_CMD = ffi::GPG_ERR_ASS_UNKNOWN_CMD,
+    ASS_SYNTAX = ffi::GPG_ERR_ASS_SYNTAX,
+    ASS_CANCELED = ffi::GPG_ERR_ASS_CANCELED,
+    ASS_NO_INPUT = ffi::GPG_ERR_ASS_NO_INPUT,
+    ASS_NO_OUTPUT = ffi::GPG_ERR_ASS_NO_OUTPUT,
+    ASS_PARAMETER = ffi::GPG_ERR_ASS_PARAMETER,
+    ASS_UNKNOWN_INQUIRE = ffi::GPG_ERR_ASS_UNKNOWN_INQUIRE,
+    ENGINE_TOO_OLD = ffi::GPG_ERR_ENGINE_TOO_OLD,
+    WINDOW_TOO_SMALL = ffi::GPG_ERR_WINDOW_TOO_SMALL,
+    WINDOW_TOO_LARGE = ffi::GPG_ERR_WINDOW_TOO_LARGE,
+    MISSING_ENVVAR = ffi::GPG_ERR_MISSING_ENVVAR,
+    USER_ID_EXISTS = ffi::GPG_ERR_USER_ID_EXISTS,
+    NAME_EXISTS = ffi::GPG_ERR_NAME_EXISTS,
+    DUP_NAME = ffi::GPG_ERR_DUP_NAME,
+    TOO_YOUNG = ffi::GPG_ERR_TOO_YOUNG,
+    TOO_OLD = ffi::GPG_ERR_TOO_OLD,
+    UNKNOWN_FLAG = ffi::GPG_ERR_UNKNOWN_FLAG,
+    INV_ORDER = ffi::GPG_ERR_INV_ORDER,
+    ALREADY_FETCHED = ffi::GPG_ERR_ALREADY_FETCHED,
+    TRY_LATER = ffi::GPG_ERR_TRY_LATER,
+    WRONG_NAME = ffi::GPG_ERR_WRONG_NAME,
+    NO_AUTH = ffi::GPG_ERR_NO_AUTH,
+    BAD_AUTH = ffi::GPG_ERR_BAD_AUTH,
+    NO_KEYBOXD = ffi::GPG_ERR_NO_KEYBOXD,
+    KEYBOXD = ffi::GPG_ERR_KEYBOXD,
+    NO_SERVICE = ffi::GPG_ERR_NO_SERVICE,
+    SERVICE = ffi::GPG_ERR_SERVICE,
+    BAD_PUK = ffi::GPG_ERR_BAD_PUK,
+    NO_RESET_CODE = ffi::GPG_ERR_NO_RESET_CODE,
+    BAD_RESET_CODE = ffi::GPG_ERR_BAD_RESET_CODE,
+    SYSTEM_BUG = ffi::GPG_ERR_SYSTEM_BUG,
+    DNS_UNKNOWN = ffi::GPG_ERR_DNS_UNKNOWN,
+    DNS_SECTION = ffi::GPG_ERR_DNS_SECTION,
+    DNS_ADDRESS = ffi::GPG_ERR_DNS_ADDRESS,
+    DNS_NO_QUERY = ffi::GPG_ERR_DNS_NO_QUERY,
+    DNS_NO_ANSWER = ffi::GPG_ERR_DNS_NO_ANSWER,
+    DNS_CLOSED = ffi::GPG_ERR_DNS_CLOSED,
+    DNS_VERIFY = ffi::GPG_ERR_DNS_VERIFY,
+    DNS_TIMEOUT = ffi::GPG_ERR_DNS_TIMEOUT,
+    LDAP_GENERAL = ffi::GPG_ERR_LDAP_GENERAL,
+    LDAP_ATTR_GENERAL = ffi::GPG_ERR_LDAP_ATTR_GENERAL,
+    LDAP_NAME_GENERAL = ffi::GPG_ERR_LDAP_NAME_GENERAL,
+    LDAP_SECURITY_GENERAL = ffi::GPG_ERR_LDAP_SECURITY_GENERAL,
+    LDAP_SERVICE_GENERAL = ffi::GPG_ERR_LDAP_SERVICE_GENERAL,
+    LDAP_UPDATE_GENERAL = ffi::GPG_ERR_LDAP_UPDATE_GENERAL,
+    LDAP_E_GENERAL = ffi::GPG_ERR_LDAP_E_GENERAL,
+    LDAP_X_GENERAL = ffi::GPG_ERR_LDAP_X_GENERAL,
+    LDAP_OTHER_GENERAL = ffi::GPG_ERR_LDAP_OTHER_GENERAL,
+    LDAP_X_CONNECTING = ffi::GPG_ERR_LDAP_X_CONNECTING,
+    LDAP_REFERRAL_LIMIT = ffi::GPG_ERR_LDAP_REFERRAL_LIMIT,
+    LDAP_CLIENT_LOOP = ffi::GPG_ERR_LDAP_CLIENT_LOOP,
+    LDAP_NO_RESULTS = ffi::GPG_ERR_LDAP_NO_RESULTS,
+    LDAP_CONTROL_NOT_FOUND = ffi::GPG_ERR_LDAP_CONTROL_NOT_FOUND,
+    LDAP_NOT_SUPPORTED = ffi::GPG_ERR_LDAP_NOT_SUPPORTED,
+    LDAP_CONNECT = ffi::GPG_ERR_LDAP_CONNECT,
+    LDAP_NO_MEMORY = ffi::GPG_ERR_LDAP_NO_MEMORY,
+    LDAP_PARAM = ffi::GPG_ERR_LDAP_PARAM,
+    LDAP_USER_CANCELLED = ffi::GPG_ERR_LDAP_USER_CANCELLED,
+    LDAP_FILTER = ffi::GPG_ERR_LDAP_FILTER,
+    LDAP_AUTH_UNKNOWN = ffi::GPG_ERR_LDAP_AUTH_UNKNOWN,
+    LDAP_TIMEOUT = ffi::GPG_ERR_LDAP_TIMEOUT,
+    LDAP_DECODING = ffi::GPG_ERR_LDAP_DECODING,
+    LDAP_ENCODING = ffi::GPG_ERR_LDAP_ENCODING,
+    LDAP_LOCAL = ffi::GPG_ERR_LDAP_LOCAL,
+    LDAP_SERVER_DOWN = ffi::GPG_ERR_LDAP_SERVER_DOWN,
+    LDAP_SUCCESS = ffi::GPG_ERR_LDAP_SUCCESS,
+    LDAP_OPERATIONS = ffi::GPG_ERR_LDAP_OPERATIONS,
+    LDAP_PROTOCOL = ffi::GPG_ERR_LDAP_PROTOCOL,
+    LDAP_TIMELIMIT = ffi::GPG_ERR_LDAP_TIMELIMIT,
+    LDAP_SIZELIMIT = ffi::GPG_ERR_LDAP_SIZELIMIT,
+    LDAP_COMPARE_FALSE = ffi::GPG_ERR_LDAP_COMPARE_FALSE,
+    LDAP_COMPARE_TRUE = ffi::GPG_ERR_LDAP_COMPARE_TRUE,
+    LDAP_UNSUPPORTED_AUTH = ffi::GPG_ERR_LDAP_UNSUPPORTED_AUTH,
+    LDAP_STRONG_AUTH_RQRD = ffi::GPG_ERR_LDAP_STRONG_AUTH_RQRD,
+    LDAP_PARTIAL_RESULTS = ffi::GPG_ERR_LDAP_PARTIAL_RESULTS,
+    LDAP_REFERRAL = ffi::GPG_ERR_LDAP_REFERRAL,
+    LDAP_ADMINLIMIT = ffi::GPG_ERR_LDAP_ADMINLIMIT,
+    LDAP_UNAVAIL_CRIT_EXTN = ffi::GPG_ERR_LDAP_UNAVAIL_CRIT_EXTN,
+    LDAP_CONFIDENT_RQRD = ffi::GPG_ERR_LDAP_CONFIDENT_RQRD,
+    LDAP_SASL_BIND_INPROG = ffi::GPG_ERR_LDAP_SASL_BIND_INPROG,
+    LDAP_NO_SUCH_ATTRIBUTE = ffi::GPG_ERR_LDAP_NO_SUCH_ATTRIBUTE,
+    LDAP_UNDEFINED_TYPE = ffi::GPG_ERR_LDAP_UNDEFINED_TYPE,
+    LDAP_BAD_MATCHING = ffi::GPG_ERR_LDAP_BAD_MATCHING,
+    LDAP_CONST_VIOLATION = ffi::GPG_ERR_LDAP_CONST_VIOLATION,
+    LDAP_TYPE_VALUE_EXISTS = ffi::GPG_ERR_LDAP_TYPE_VALUE_EXISTS,
+    LDAP_INV_SYNTAX = ffi::GPG_ERR_LDAP_INV_SYNTAX,
+    LDAP_NO_SUCH_OBJ = ffi::GPG_ERR_LDAP_NO_SUCH_OBJ,
+    LDAP_ALIAS_PROBLEM = ffi::GPG_ERR_LDAP_ALIAS_PROBLEM,
+    LDAP_INV_DN_SYNTAX = ffi::GPG_ERR_LDAP_INV_DN_SYNTAX,
+    LDAP_IS_LEAF = ffi::GPG_ERR_LDAP_IS_LEAF,
+    LDAP_ALIAS_DEREF = ffi::GPG_ERR_LDAP_ALIAS_DEREF,
+    LDAP_X_PROXY_AUTH_FAIL = ffi::GPG_ERR_LDAP_X_PROXY_AUTH_FAIL,
+    LDAP_BAD_AUTH = ffi::GPG_ERR_LDAP_BAD_AUTH,
+    LDAP_INV_CREDENTIALS = ffi::GPG_ERR_LDAP_INV_CREDENTIALS,
+    LDAP_INSUFFICIENT_ACC = ffi::GPG_ERR_LDAP_INSUFFICIENT_ACC,
+    LDAP_BUSY = ffi::GPG_ERR_LDAP_BUSY,
+    LDAP_UNAVAILABLE = ffi::GPG_ERR_LDAP_UNAVAILABLE,
+    LDAP_UNWILL_TO_PERFORM = ffi::GPG_ERR_LDAP_UNWILL_TO_PERFORM,
+    LDAP_LOOP_DETECT = ffi::GPG_ERR_LDAP_LOOP_DETECT,
+    LDAP_NAMING_VIOLATION = ffi::GPG_ERR_LDAP_NAMING_VIOLATION,
+    LDAP_OBJ_CLS_VIOLATION = ffi::GPG_ERR_LDAP_OBJ_CLS_VIOLATION,
+    LDAP_NOT_ALLOW_NONLEAF = ffi::GPG_ERR_LDAP_NOT_ALLOW_NONLEAF,
+    LDAP_NOT_ALLOW_ON_RDN = ffi::GPG_ERR_LDAP_NOT_ALLOW_ON_RDN,
+    LDAP_ALREADY_EXISTS = ffi::GPG_ERR_LDAP_ALREADY_EXISTS,
+    LDAP_NO_OBJ_CLASS_MODS = ffi::GPG_ERR_LDAP_NO_OBJ_CLASS_MODS,
+    LDAP_RESULTS_TOO_LARGE = ffi::GPG_ERR_LDAP_RESULTS_TOO_LARGE,
+    LDAP_AFFECTS_MULT_DSAS = ffi::GPG_ERR_LDAP_AFFECTS_MULT_DSAS,
+    LDAP_VLV = ffi::GPG_ERR_LDAP_VLV,
+    LDAP_OTHER = ffi::GPG_ERR_LDAP_OTHER,
+    LDAP_CUP_RESOURCE_LIMIT = ffi::GPG_ERR_LDAP_CUP_RESOURCE_LIMIT,
+    LDAP_CUP_SEC_VIOLATION = ffi::GPG_ERR_LDAP_CUP_SEC_VIOLATION,
+    LDAP_CUP_INV_DATA = ffi::GPG_ERR_LDAP_CUP_INV_DATA,
+    LDAP_CUP_UNSUP_SCHEME = ffi::GPG_ERR_LDAP_CUP_UNSUP_SCHEME,
+    LDAP_CUP_RELOAD = ffi::GPG_ERR_LDAP_CUP_RELOAD,
+    LDAP_CANCELLED = ffi::GPG_ERR_LDAP_CANCELLED,
+    LDAP_NO_SUCH_OPERATION = ffi::GPG_ERR_LDAP_NO_SUCH_OPERATION,
+    LDAP_TOO_LATE = ffi::GPG_ERR_LDAP_TOO_LATE,
+    LDAP_CANNOT_CANCEL = ffi::GPG_ERR_LDAP_CANNOT_CANCEL,
+    LDAP_ASSERTION_FAILED = ffi::GPG_ERR_LDAP_ASSERTION_FAILED,
+    LDAP_PROX_AUTH_DENIED = ffi::GPG_ERR_LDAP_PROX_AUTH_DENIED,
+    USER_1 = ffi::GPG_ERR_USER_1,
+    USER_2 = ffi::GPG_ERR_USER_2,
+    USER_3 = ffi::GPG_ERR_USER_3,
+    USER_4 = ffi::GPG_ERR_USER_4,
+    USER_5 = ffi::GPG_ERR_USER_5,
+    USER_6 = ffi::GPG_ERR_USER_6,
+    USER_7 = ffi::GPG_ERR_USER_7,
+    USER_8 = ffi::GPG_ERR_USER_8,
+    USER_9 = ffi::GPG_ERR_USER_9,
+    USER_10 = ffi::GPG_ERR_USER_10,
+    USER_11 = ffi::GPG_ERR_USER_11,
+    USER_12 = ffi::GPG_ERR_USER_12,
+    USER_13 = ffi::GPG_ERR_USER_13,
+    USER_14 = ffi::GPG_ERR_USER_14,
+    USER_15 = ffi::GPG_ERR_USER_15,
+    USER_16 = ffi::GPG_ERR_USER_16,
+    SQL_OK = ffi::GPG_ERR_SQL_OK,
+    SQL_ERROR = ffi::GPG_ERR_SQL_ERROR,
+    SQL_INTERNAL = ffi::GPG_ERR_SQL_INTERNAL,
+    SQL_PERM = ffi::GPG_ERR_SQL_PERM,
+    SQL_ABORT = ffi::GPG_ERR_SQL_ABORT,
+    SQL_BUSY = ffi::GPG_ERR_SQL_BUSY,
+    SQL_LOCKED = ffi::GPG_ERR_SQL_LOCKED,
+    SQL_NOMEM = ffi::GPG_ERR_SQL_NOMEM,
+    SQL_READONLY = ffi::GPG_ERR_SQL_READONLY,
+    SQL_INTERRUPT = ffi::GPG_ERR_SQL_INTERRUPT,
+    SQL_IOERR = ffi::GPG_ERR_SQL_IOERR,
+    SQL_CORRUPT = ffi::GPG_ERR_SQL_CORRUPT,
+    SQL_NOTFOUND = ffi::GPG_ERR_SQL_NOTFOUND,
+    SQL_FULL = ffi::GPG_ERR_SQL_FULL,
+    SQL_CANTOPEN = ffi::GPG_ERR_SQL_CANTOPEN,
+    SQL_PROTOCOL = ffi::GPG_ERR_SQL_PROTOCOL,
+    SQL_EMPTY = ffi::GPG_ERR_SQL_EMPTY,
+    SQL_SCHEMA = ffi::GPG_ERR_SQL_SCHEMA,
+    SQL_TOOBIG = ffi::GPG_ERR_SQL_TOOBIG,
+    SQL_CONSTRAINT = ffi::GPG_ERR_SQL_CONSTRAINT,
+    SQL_MISMATCH = ffi::GPG_ERR_SQL_MISMATCH,
+    SQL_MISUSE = ffi::GPG_ERR_SQL_MISUSE,
+    SQL_NOLFS = ffi::GPG_ERR_SQL_NOLFS,
+    SQL_AUTH = ffi::GPG_ERR_SQL_AUTH,
+    SQL_FORMAT = ffi::GPG_ERR_SQL_FORMAT,
+    SQL_RANGE = ffi::GPG_ERR_SQL_RANGE,
+    SQL_NOTADB = ffi::GPG_ERR_SQL_NOTADB,
+    SQL_NOTICE = ffi::GPG_ERR_SQL_NOTICE,
+    SQL_WARNING = ffi::GPG_ERR_SQL_WARNING,
+    SQL_ROW = ffi::GPG_ERR_SQL_ROW,
+    SQL_DONE = ffi::GPG_ERR_SQL_DONE,
+    MISSING_ERRNO = ffi::GPG_ERR_MISSING_ERRNO,
+    UNKNOWN_ERRNO = ffi::GPG_ERR_UNKNOWN_ERRNO,
+    EOF = ffi::GPG_ERR_EOF,
+    E2BIG = ffi::GPG_ERR_E2BIG,
+    EACCES = ffi::GPG_ERR_EACCES,
+    EADDRINUSE = ffi::GPG_ERR_EADDRINUSE,
+    EADDRNOTAVAIL = ffi::GPG_ERR_EADDRNOTAVAIL,
+    EADV = ffi::GPG_ERR_EADV,
+    EAFNOSUPPORT = ffi::GPG_ERR_EAFNOSUPPORT,
+    EAGAIN = ffi::GPG_ERR_EAGAIN,
+    EALREADY = ffi::GPG_ERR_EALREADY,
+    EAUTH = ffi::GPG_ERR_EAUTH,
+    EBACKGROUND = ffi::GPG_ERR_EBACKGROUND,
+    EBADE = ffi::GPG_ERR_EBADE,
+    EBADF = ffi::GPG_ERR_EBADF,
+    EBADFD = ffi::GPG_ERR_EBADFD,
+    EBADMSG = ffi::GPG_ERR_EBADMSG,
+    EBADR = ffi::GPG_ERR_EBADR,
+    EBADRPC = ffi::GPG_ERR_EBADRPC,
+    EBADRQC = ffi::GPG_ERR_EBADRQC,
+    EBADSLT = ffi::GPG_ERR_EBADSLT,
+    EBFONT = ffi::GPG_ERR_EBFONT,
+    EBUSY = ffi::GPG_ERR_EBUSY,
+    ECANCELED = ffi::GPG_ERR_ECANCELED,
+    ECHILD = ffi::GPG_ERR_ECHILD,
+    ECHRNG = ffi::GPG_ERR_ECHRNG,
+    ECOMM = ffi::GPG_ERR_ECOMM,
+    ECONNABORTED = ffi::GPG_ERR_ECONNABORTED,
+    ECONNREFUSED = ffi::GPG_ERR_ECONNREFUSED,
+    ECONNRESET = ffi::GPG_ERR_ECONNRESET,
+    ED = ffi::GPG_ERR_ED,
+    EDEADLK = ffi::GPG_ERR_EDEADLK,
+    EDEADLOCK = ffi::GPG_ERR_EDEADLOCK,
+    EDESTADDRREQ = ffi::GPG_ERR_EDESTADDRREQ,
+    EDIED = ffi::GPG_ERR_EDIED,
+    EDOM = ffi::GPG_ERR_EDOM,
+    EDOTDOT = ffi::GPG_ERR_EDOTDOT,
+    EDQUOT = ffi::GPG_ERR_EDQUOT,
+    EEXIST = ffi::GPG_ERR_EEXIST,
+    EFAULT = ffi::GPG_ERR_EFAULT,
+    EFBIG = ffi::GPG_ERR_EFBIG,
+    EFTYPE = ffi::GPG_ERR_EFTYPE,
+    EGRATUITOUS = ffi::GPG_ERR_EGRATUITOUS,
+    EGREGIOUS = ffi::GPG_ERR_EGREGIOUS,
+    EHOSTDOWN = ffi::GPG_ERR_EHOSTDOWN,
+    EHOSTUNREACH = ffi::GPG_ERR_EHOSTUNREACH,
+    EIDRM = ffi::GPG_ERR_EIDRM,
+    EIEIO = ffi::GPG_ERR_EIEIO,
+    EILSEQ = ffi::GPG_ERR_EILSEQ,
+    EINPROGRESS = ffi::GPG_ERR_EINPROGRESS,
+    EINTR = ffi::GPG_ERR_EINTR,
+    EINVAL = ffi::GPG_ERR_EINVAL,
+    EIO = ffi::GPG_ERR_EIO,
+    EISCONN = ffi::GPG_ERR_EISCONN,
+    EISDIR = ffi::GPG_ERR_EISDIR,
+    EISNAM = ffi::GPG_ERR_EISNAM,
+    EL2HLT = ffi::GPG_ERR_EL2HLT,
+    EL2NSYNC = ffi::GPG_ERR_EL2NSYNC,
+    EL3HLT = ffi::GPG_ERR_EL3HLT,
+    EL3RST = ffi::GPG_ERR_EL3RST,
+    ELIBACC = ffi::GPG_ERR_ELIBACC,
+    ELIBBAD = ffi::GPG_ERR_ELIBBAD,
+    ELIBEXEC = ffi::GPG_ERR_ELIBEXEC,
+    ELIBMAX = ffi::GPG_ERR_ELIBMAX,
+    ELIBSCN = ffi::GPG_ERR_ELIBSCN,
+    ELNRNG = ffi::GPG_ERR_ELNRNG,
+    ELOOP = ffi::GPG_ERR_ELOOP,
+    EMEDIUMTYPE = ffi::GPG_ERR_EMEDIUMTYPE,
+    EMFILE = ffi::GPG_ERR_EMFILE,
+    EMLINK = ffi::GPG_ERR_EMLINK,
+    EMSGSIZE = ffi::GPG_ERR_EMSGSIZE,
+    EMULTIHOP = ffi::GPG_ERR_EMULTIHOP,
+    ENAMETOOLONG = ffi::GPG_ERR_ENAMETOOLONG,
+    ENAVAIL = ffi::GPG_ERR_ENAVAIL,
+    ENEEDAUTH = ffi::GPG_ERR_ENEEDAUTH,
+    ENETDOWN = ffi::GPG_ERR_ENETDOWN,
+    ENETRESET = ffi::GPG_ERR_ENETRESET,
+    ENETUNREACH = ffi::GPG_ERR_ENETUNREACH,
+    ENFILE = ffi::GPG_ERR_ENFILE,
+    ENOANO = ffi::GPG_ERR_ENOANO,
+    ENOBUFS = ffi::GPG_ERR_ENOBUFS,
+    ENOCSI = ffi::GPG_ERR_ENOCSI,
+    ENODATA = ffi::GPG_ERR_ENODATA,
+    ENODEV = ffi::GPG_ERR_ENODEV,
+    ENOENT = ffi::GPG_ERR_ENOENT,
+    ENOEXEC = ffi::GPG_ERR_ENOEXEC,
+    ENOLCK = ffi::GPG_ERR_ENOLCK,
+    ENOLINK = ffi::GPG_ERR_ENOLINK,
+    ENOMEDIUM = ffi::GPG_ERR_ENOMEDIUM,
+    ENOMEM = ffi::GPG_ERR_ENOMEM,
+    ENOMSG = ffi::GPG_ERR_ENOMSG,
+    ENONET = ffi::GPG_ERR_ENONET,
+    ENOPKG = ffi::GPG_ERR_ENOPKG,
+    ENOPROTOOPT = ffi::GPG_ERR_ENOPROTOOPT,
+    ENOSPC = ffi::GPG_ERR_ENOSPC,
+    ENOSR = ffi::GPG_ERR_ENOSR,
+    ENOSTR = ffi::GPG_ERR_ENOSTR,
+    ENOSYS = ffi::GPG_ERR_ENOSYS,
+    ENOTBLK = ffi::GPG_ERR_ENOTBLK,
+    ENOTCONN = ffi::GPG_ERR_ENOTCONN,
+    ENOTDIR = ffi::GPG_ERR_ENOTDIR,
+    ENOTEMPTY = ffi::GPG_ERR_ENOTEMPTY,
+    ENOTNAM = ffi::GPG_ERR_ENOTNAM,
+    ENOTSOCK = ffi::GPG_ERR_ENOTSOCK,
+    ENOTSUP = ffi::GPG_ERR_ENOTSUP,
+    ENOTTY = ffi::GPG_ERR_ENOTTY,
+    ENOTUNIQ = ffi::GPG_ERR_ENOTUNIQ,
+    ENXIO = ffi::GPG_ERR_ENXIO,
+    EOPNOTSUPP = ffi::GPG_ERR_EOPNOTSUPP,
+    EOVERFLOW = ffi::GPG_ERR_EOVERFLOW,
+    EPERM = ffi::GPG_ERR_EPERM,
+    EPFNOSUPPORT = ffi::GPG_ERR_EPFNOSUPPORT,
+    EPIPE = ffi::GPG_ERR_EPIPE,
+    EPROCLIM = ffi::GPG_ERR_EPROCLIM,
+    EPROCUNAVAIL = ffi::GPG_ERR_EPROCUNAVAIL,
+    EPROGMISMATCH = ffi::GPG_ERR_EPROGMISMATCH,
+    EPROGUNAVAIL = ffi::GPG_ERR_EPROGUNAVAIL,
+    EPROTO = ffi::GPG_ERR_EPROTO,
+    EPROTONOSUPPORT = ffi::GPG_ERR_EPROTONOSUPPORT,
+    EPROTOTYPE = ffi::GPG_ERR_EPROTOTYPE,
+    ERANGE = ffi::GPG_ERR_ERANGE,
+    EREMCHG = ffi::GPG_ERR_EREMCHG,
+    EREMOTE = ffi::GPG_ERR_EREMOTE,
+    EREMOTEIO = ffi::GPG_ERR_EREMOTEIO,
+    ERESTART = ffi::GPG_ERR_ERESTART,
+    EROFS = ffi::GPG_ERR_EROFS,
+    ERPCMISMATCH = ffi::GPG_ERR_ERPCMISMATCH,
+    ESHUTDOWN = ffi::GPG_ERR_ESHUTDOWN,
+    ESOCKTNOSUPPORT = ffi::GPG_ERR_ESOCKTNOSUPPORT,
+    ESPIPE = ffi::GPG_ERR_ESPIPE,
+    ESRCH = ffi::GPG_ERR_ESRCH,
+    ESRMNT = ffi::GPG_ERR_ESRMNT,
+    ESTALE = ffi::GPG_ERR_ESTALE,
+    ESTRPIPE = ffi::GPG_ERR_ESTRPIPE,
+    ETIME = ffi::GPG_ERR_ETIME,
+    ETIMEDOUT = ffi::GPG_ERR_ETIMEDOUT,
+    ETOOMANYREFS = ffi::GPG_ERR_ETOOMANYREFS,
+    ETXTBSY = ffi::GPG_ERR_ETXTBSY,
+    EUCLEAN = ffi::GPG_ERR_EUCLEAN,
+    EUNATCH = ffi::GPG_ERR_EUNATCH,
+    EUSERS = ffi::GPG_ERR_EUSERS,
+    EWOULDBLOCK = ffi::GPG_ERR_EWOULDBLOCK,
+    EXDEV = ffi::GPG_ERR_EXDEV,
+    EXFULL = ffi::GPG_ERR_EXFULL,
+}
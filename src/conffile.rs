@@ -0,0 +1,405 @@
+//! A parser for the option file syntax used by GnuPG and libgpg-error.
+//!
+//! Unlike [`argparse`](crate::argparse), this module does not use the C
+//! library and does not check option names, which makes it suitable for
+//! validating files such as `gpg.conf` on systems without GnuPG.
+//!
+//! Each line holds an option name, optionally followed by whitespace and an
+//! argument, which may be enclosed in double quotes. Lines starting with `#`
+//! are comments. Lines starting with `[` are meta commands:
+//!
+//! * `[ignore]`, `[+ignore]` and `[-ignore]` toggle the ignore attribute.
+//! * `[force]`, `[+force]` and `[-force]` toggle the force attribute.
+//! * `[user NAME]` starts a section that only applies to the user `NAME`
+//!   (or to all users if `NAME` is `*`).
+//! * `[if CONDITION]`, `[else]` and `[fi]` only apply the enclosed lines
+//!   if `CONDITION` holds, and may be nested.
+//! * `[echo TEXT]`, `[info TEXT]` and `[verbose]` are accepted and skipped.
+//!
+//! A condition is either a single value, which holds if it is neither empty
+//! nor `0` and may be negated with a leading `!`, or two values compared with
+//! `==` or `!=` as strings or with `-lt`, `-le`, `-gt` or `-ge` as integers.
+//! Values starting with `$` are replaced by the variable of that name as set
+//! with [`Parser::var`], or by the user name for `$user`; unset variables
+//! are empty.
+//!
+//! The `force`, `user` and `if` commands are only allowed in global
+//! configuration files such as `gpgrt.conf`.
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use crate::{Error, ParseError};
+
+/// An option read from an option file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Entry {
+    /// The name of the option, without leading dashes.
+    pub name: String,
+    /// The argument of the option, with any quotes removed.
+    pub value: Option<String>,
+    /// The line the option was read from, starting at 1.
+    pub line: u32,
+    /// The option was given while the force attribute was set.
+    pub force: bool,
+    /// The option was given while the ignore attribute was set.
+    pub ignore: bool,
+}
+
+/// Parses option files.
+#[derive(Debug, Clone, Default)]
+pub struct Parser {
+    global: bool,
+    user: Option<String>,
+    vars: BTreeMap<String, String>,
+}
+
+impl Parser {
+    /// Creates a parser for a per-user option file.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the file is a global configuration file, where the
+    /// `force` and `user` meta commands are allowed.
+    #[inline]
+    pub fn global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
+
+    /// Sets the name of the user `[user NAME]` sections are matched against.
+    ///
+    /// Without a user name only `[user *]` sections apply.
+    #[inline]
+    pub fn user(mut self, name: impl Into<String>) -> Self {
+        self.user = Some(name.into());
+        self
+    }
+
+    /// Sets the variable `name`, which `[if]` conditions refer to as `$name`.
+    #[inline]
+    pub fn var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.vars.insert(name.into(), value.into());
+        self
+    }
+
+    /// Parses the option file at `path`.
+    pub fn parse_file(&self, path: impl AsRef<Path>) -> Result<Vec<Entry>, ParseError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| ParseError::new(e.into(), 0).with_file(path))?;
+        self.parse(BufReader::new(file))
+            .map_err(|e| e.with_file(path))
+    }
+
+    /// Parses an option file from `reader`.
+    pub fn parse(&self, reader: impl BufRead) -> Result<Vec<Entry>, ParseError> {
+        let mut state = State {
+            force: false,
+            ignore: false,
+            user: true,
+            conditions: Vec::new(),
+        };
+        let mut entries = Vec::new();
+        for (idx, line) in reader.split(b'\n').enumerate() {
+            let line_no = u32::try_from(idx + 1).unwrap_or(u32::MAX);
+            let fail = |error| ParseError::new(error, line_no);
+            let line = line.map_err(|e| fail(e.into()))?;
+            let line = String::from_utf8(line).map_err(|_| fail(Error::INV_VALUE))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(meta) = line.strip_prefix('[') {
+                self.meta(&mut state, meta).map_err(fail)?;
+            } else {
+                let (name, value) = parse_option(line).map_err(fail)?;
+                if state.active() {
+                    entries.push(Entry {
+                        name,
+                        value,
+                        line: line_no,
+                        force: state.force,
+                        ignore: state.ignore,
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Parses an option file from a string.
+    #[inline]
+    pub fn parse_str(&self, s: &str) -> Result<Vec<Entry>, ParseError> {
+        self.parse(io::Cursor::new(s))
+    }
+
+    fn meta(&self, state: &mut State, meta: &str) -> Result<(), Error> {
+        let (meta, rest) = meta.split_once(']').ok_or(Error::SYNTAX)?;
+        if !rest.trim().is_empty() {
+            return Err(Error::SYNTAX);
+        }
+        let meta = meta.trim();
+        let (cmd, args) = match meta.split_once(char::is_whitespace) {
+            Some((cmd, args)) => (cmd, args.trim()),
+            None => (meta, ""),
+        };
+        match cmd {
+            "user" | "force" | "+force" | "-force" | "if" | "else" | "fi" if !self.global => {
+                Err(Error::SYNTAX)
+            }
+            "user" => {
+                if args.is_empty() {
+                    return Err(Error::INV_VALUE);
+                }
+                state.user = args == "*" || self.user.as_deref() == Some(args);
+                state.force = false;
+                state.ignore = false;
+                Ok(())
+            }
+            "force" | "+force" | "-force" | "ignore" | "+ignore" | "-ignore" => {
+                if !args.is_empty() {
+                    return Err(Error::INV_VALUE);
+                }
+                if state.active() {
+                    let on = !cmd.starts_with('-');
+                    match cmd.trim_start_matches(['+', '-']) {
+                        "force" => state.force = on,
+                        _ => state.ignore = on,
+                    }
+                }
+                Ok(())
+            }
+            "if" => {
+                let holds = self.eval(args)?;
+                state.conditions.push(holds);
+                Ok(())
+            }
+            "else" | "fi" => {
+                if !args.is_empty() {
+                    return Err(Error::INV_VALUE);
+                }
+                // Like libgpg-error, ignore these outside of a conditional.
+                if cmd == "fi" {
+                    state.conditions.pop();
+                } else if let Some(holds) = state.conditions.last_mut() {
+                    *holds = !*holds;
+                }
+                Ok(())
+            }
+            "echo" | "-echo" | "info" | "-info" => Ok(()),
+            "verbose" | "+verbose" | "-verbose" => {
+                if !args.is_empty() {
+                    return Err(Error::INV_VALUE);
+                }
+                Ok(())
+            }
+            _ => Err(Error::SYNTAX),
+        }
+    }
+
+    fn expand<'a>(&'a self, value: &'a str) -> &'a str {
+        match value.strip_prefix('$') {
+            Some("user") => self.user.as_deref().unwrap_or(""),
+            Some(name) => self.vars.get(name).map_or("", |v| v.as_str()),
+            None => value,
+        }
+    }
+
+    fn eval(&self, cond: &str) -> Result<bool, Error> {
+        let number = |v: &str| v.parse::<i64>().map_err(|_| Error::INV_VALUE);
+        match *cond.split_whitespace().collect::<Vec<_>>() {
+            [value] => {
+                let (negate, value) = match value.strip_prefix('!') {
+                    Some(value) => (true, value),
+                    None => (false, value),
+                };
+                let value = self.expand(value);
+                Ok(negate == (value.is_empty() || value == "0"))
+            }
+            [a, op, b] => {
+                let (a, b) = (self.expand(a), self.expand(b));
+                match op {
+                    "==" => Ok(a == b),
+                    "!=" => Ok(a != b),
+                    "-lt" => Ok(number(a)? < number(b)?),
+                    "-le" => Ok(number(a)? <= number(b)?),
+                    "-gt" => Ok(number(a)? > number(b)?),
+                    "-ge" => Ok(number(a)? >= number(b)?),
+                    _ => Err(Error::INV_VALUE),
+                }
+            }
+            _ => Err(Error::INV_VALUE),
+        }
+    }
+}
+
+struct State {
+    force: bool,
+    ignore: bool,
+    user: bool,
+    conditions: Vec<bool>,
+}
+
+impl State {
+    fn active(&self) -> bool {
+        self.user && self.conditions.iter().all(|&holds| holds)
+    }
+}
+
+fn parse_option(line: &str) -> Result<(String, Option<String>), Error> {
+    let (name, arg) = match line.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim_start()),
+        None => (line, ""),
+    };
+    let name = name.trim_start_matches("--");
+    if name.is_empty()
+        || !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(Error::SYNTAX);
+    }
+    let value = if arg.is_empty() {
+        None
+    } else if let Some(quoted) = arg.strip_prefix('"') {
+        Some(quoted.strip_suffix('"').ok_or(Error::INV_VALUE)?.to_owned())
+    } else {
+        Some(arg.to_owned())
+    };
+    Ok((name.to_owned(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        let text = "# comment\n\
+                    verbose\n\
+                    \n  keyserver hkps://keys.example.org  \n\
+                    comment \"two  words\"\n";
+        let entries = Parser::new().parse_str(text).unwrap();
+        let parsed: Vec<_> = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.value.as_deref(), e.line))
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                ("verbose", None, 2),
+                ("keyserver", Some("hkps://keys.example.org"), 4),
+                ("comment", Some("two  words"), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_meta() {
+        let text = "[ignore]\n\
+                    a\n\
+                    [-ignore]\n\
+                    [force]\n\
+                    b\n\
+                    [user alice]\n\
+                    c\n\
+                    [user bob]\n\
+                    d\n\
+                    [user *]\n\
+                    e\n";
+        let entries = Parser::new()
+            .global(true)
+            .user("bob")
+            .parse_str(text)
+            .unwrap();
+        let parsed: Vec<_> = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.force, e.ignore))
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                ("a", false, true),
+                ("b", true, false),
+                ("d", false, false),
+                ("e", false, false),
+            ]
+        );
+
+        let err = Parser::new().parse_str("a\n[force]\n").unwrap_err();
+        assert_eq!((err.error(), err.line()), (Error::SYNTAX, 2));
+    }
+
+    #[test]
+    fn test_conditions() {
+        let text = "[if $user == bob]\n\
+                    a\n\
+                    [if !$debug]\n\
+                    b\n\
+                    [else]\n\
+                    c\n\
+                    [fi]\n\
+                    [else]\n\
+                    d\n\
+                    [if 1]\n\
+                    e\n\
+                    [fi]\n\
+                    [fi]\n\
+                    [if $level -ge 10]\n\
+                    [force]\n\
+                    f\n\
+                    [fi]\n\
+                    g\n";
+        let names = |parser: Parser| -> Vec<_> {
+            parser
+                .global(true)
+                .parse_str(text)
+                .unwrap()
+                .into_iter()
+                .map(|e| (e.name, e.force))
+                .collect()
+        };
+        let entry = |name: &str, force| (name.to_owned(), force);
+        assert_eq!(
+            names(Parser::new().user("bob").var("level", "9")),
+            [entry("a", false), entry("b", false), entry("g", false)]
+        );
+        assert_eq!(
+            names(
+                Parser::new()
+                    .user("alice")
+                    .var("debug", "yes")
+                    .var("level", "10")
+            ),
+            [
+                entry("d", false),
+                entry("e", false),
+                entry("f", true),
+                entry("g", true)
+            ]
+        );
+
+        let err = Parser::new().parse_str("[if 1]\n").unwrap_err();
+        assert_eq!(err.error(), Error::SYNTAX);
+        let err = Parser::new()
+            .global(true)
+            .parse_str("[if $x -lt y]\n")
+            .unwrap_err();
+        assert_eq!(err.error(), Error::INV_VALUE);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = Parser::new().parse_str("a \"unterminated\n").unwrap_err();
+        assert_eq!((err.error(), err.line()), (Error::INV_VALUE, 1));
+        let err = Parser::new().parse_str("\n[bogus]\n").unwrap_err();
+        assert_eq!((err.error(), err.line()), (Error::SYNTAX, 2));
+        assert!(err.to_string().starts_with("<input>:2: "));
+        let err = Parser::new().parse_str("foo=bar\n").unwrap_err();
+        assert_eq!(err.error(), Error::SYNTAX);
+    }
+}
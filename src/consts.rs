@@ -1,28 +1,28 @@
 impl Error{
-pub const SOURCE_UNKNOWN: ErrorSource = ffi::GPG_ERR_SOURCE_UNKNOWN;
-pub const SOURCE_GCRYPT: ErrorSource = ffi::GPG_ERR_SOURCE_GCRYPT;
-pub const SOURCE_GPG: ErrorSource = ffi::GPG_ERR_SOURCE_GPG;
-pub const SOURCE_GPGSM: ErrorSource = ffi::GPG_ERR_SOURCE_GPGSM;
-pub const SOURCE_GPGAGENT: ErrorSource = ffi::GPG_ERR_SOURCE_GPGAGENT;
-pub const SOURCE_PINENTRY: ErrorSource = ffi::GPG_ERR_SOURCE_PINENTRY;
-pub const SOURCE_SCD: ErrorSource = ffi::GPG_ERR_SOURCE_SCD;
-pub const SOURCE_GPGME: ErrorSource = ffi::GPG_ERR_SOURCE_GPGME;
-pub const SOURCE_KEYBOX: ErrorSource = ffi::GPG_ERR_SOURCE_KEYBOX;
-pub const SOURCE_KSBA: ErrorSource = ffi::GPG_ERR_SOURCE_KSBA;
-pub const SOURCE_DIRMNGR: ErrorSource = ffi::GPG_ERR_SOURCE_DIRMNGR;
-pub const SOURCE_GSTI: ErrorSource = ffi::GPG_ERR_SOURCE_GSTI;
-pub const SOURCE_GPA: ErrorSource = ffi::GPG_ERR_SOURCE_GPA;
-pub const SOURCE_KLEO: ErrorSource = ffi::GPG_ERR_SOURCE_KLEO;
-pub const SOURCE_G13: ErrorSource = ffi::GPG_ERR_SOURCE_G13;
-pub const SOURCE_ASSUAN: ErrorSource = ffi::GPG_ERR_SOURCE_ASSUAN;
-pub const SOURCE_TPM2D: ErrorSource = ffi::GPG_ERR_SOURCE_TPM2D;
-pub const SOURCE_TLS: ErrorSource = ffi::GPG_ERR_SOURCE_TLS;
-pub const SOURCE_TKD: ErrorSource = ffi::GPG_ERR_SOURCE_TKD;
-pub const SOURCE_ANY: ErrorSource = ffi::GPG_ERR_SOURCE_ANY;
-pub const SOURCE_USER_1: ErrorSource = ffi::GPG_ERR_SOURCE_USER_1;
-pub const SOURCE_USER_2: ErrorSource = ffi::GPG_ERR_SOURCE_USER_2;
-pub const SOURCE_USER_3: ErrorSource = ffi::GPG_ERR_SOURCE_USER_3;
-pub const SOURCE_USER_4: ErrorSource = ffi::GPG_ERR_SOURCE_USER_4;
+pub const SOURCE_UNKNOWN: ErrorSource = ErrorSource::UNKNOWN;
+pub const SOURCE_GCRYPT: ErrorSource = ErrorSource::GCRYPT;
+pub const SOURCE_GPG: ErrorSource = ErrorSource::GPG;
+pub const SOURCE_GPGSM: ErrorSource = ErrorSource::GPGSM;
+pub const SOURCE_GPGAGENT: ErrorSource = ErrorSource::GPGAGENT;
+pub const SOURCE_PINENTRY: ErrorSource = ErrorSource::PINENTRY;
+pub const SOURCE_SCD: ErrorSource = ErrorSource::SCD;
+pub const SOURCE_GPGME: ErrorSource = ErrorSource::GPGME;
+pub const SOURCE_KEYBOX: ErrorSource = ErrorSource::KEYBOX;
+pub const SOURCE_KSBA: ErrorSource = ErrorSource::KSBA;
+pub const SOURCE_DIRMNGR: ErrorSource = ErrorSource::DIRMNGR;
+pub const SOURCE_GSTI: ErrorSource = ErrorSource::GSTI;
+pub const SOURCE_GPA: ErrorSource = ErrorSource::GPA;
+pub const SOURCE_KLEO: ErrorSource = ErrorSource::KLEO;
+pub const SOURCE_G13: ErrorSource = ErrorSource::G13;
+pub const SOURCE_ASSUAN: ErrorSource = ErrorSource::ASSUAN;
+pub const SOURCE_TPM2D: ErrorSource = ErrorSource::TPM2D;
+pub const SOURCE_TLS: ErrorSource = ErrorSource::TLS;
+pub const SOURCE_TKD: ErrorSource = ErrorSource::TKD;
+pub const SOURCE_ANY: ErrorSource = ErrorSource::ANY;
+pub const SOURCE_USER_1: ErrorSource = ErrorSource::USER_1;
+pub const SOURCE_USER_2: ErrorSource = ErrorSource::USER_2;
+pub const SOURCE_USER_3: ErrorSource = ErrorSource::USER_3;
+pub const SOURCE_USER_4: ErrorSource = ErrorSource::USER_4;
 pub const NO_ERROR: Self = Self(ffi::GPG_ERR_NO_ERROR);
 pub const GENERAL: Self = Self(ffi::GPG_ERR_GENERAL);
 pub const UNKNOWN_PACKET: Self = Self(ffi::GPG_ERR_UNKNOWN_PACKET);
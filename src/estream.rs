@@ -0,0 +1,579 @@
+//! Safe wrappers for libgpg-error's estream API.
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
+    mem,
+    os::raw::{c_int, c_void},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{Error, Result};
+
+const SEEK_SET: c_int = 0;
+const SEEK_CUR: c_int = 1;
+const SEEK_END: c_int = 2;
+
+const BUF_SIZE: usize = 8 * 1024;
+
+#[cfg(unix)]
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(CString::new(path.as_os_str().as_bytes())?)
+}
+
+#[cfg(not(unix))]
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    Ok(CString::new(path.to_str().ok_or(Error::EINVAL)?)?)
+}
+
+/// An owned estream handle.
+///
+/// Data read through [`BufRead`] is buffered on the Rust side; any of it that
+/// has not been consumed is given back to the underlying stream (by seeking
+/// backwards) before the stream is written to or repositioned.
+pub struct Stream {
+    raw: NonNull<ffi::_gpgrt__stream>,
+    buf: Box<[u8]>,
+    pos: usize,
+    filled: usize,
+    memory: bool,
+}
+
+unsafe impl Send for Stream {}
+
+impl Drop for Stream {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_fclose(self.as_raw());
+        }
+    }
+}
+
+impl Stream {
+    /// Creates a `Stream` from a raw stream handle, taking ownership of it.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid estream handle that is not closed elsewhere.
+    #[inline]
+    pub unsafe fn from_raw(raw: ffi::gpgrt_stream_t) -> Self {
+        Self {
+            raw: NonNull::new(raw).expect("estream handle is null"),
+            buf: Box::default(),
+            pos: 0,
+            filled: 0,
+            memory: false,
+        }
+    }
+
+    unsafe fn from_result(raw: ffi::gpgrt_stream_t) -> Result<Self> {
+        if raw.is_null() {
+            Err(Error::last_os_error())
+        } else {
+            Ok(Self::from_raw(raw))
+        }
+    }
+
+    /// Returns the raw stream handle.
+    ///
+    /// Data buffered by [`BufRead::fill_buf`] that has not been consumed is
+    /// not visible through the raw handle.
+    #[inline]
+    pub fn as_raw(&self) -> ffi::gpgrt_stream_t {
+        self.raw.as_ptr()
+    }
+
+    /// Returns the raw stream handle, releasing ownership of it.
+    #[inline]
+    pub fn into_raw(mut self) -> ffi::gpgrt_stream_t {
+        let _ = self.discard_buffer();
+        let raw = self.as_raw();
+        mem::forget(self);
+        raw
+    }
+
+    /// Opens the file at `path` with the given `fopen`-style mode.
+    pub fn open(path: impl AsRef<Path>, mode: &str) -> Result<Self> {
+        let path = path_to_cstring(path.as_ref())?;
+        let mode = CString::new(mode)?;
+        unsafe { Self::from_result(ffi::gpgrt_fopen(path.as_ptr(), mode.as_ptr())) }
+    }
+
+    /// Creates a new, empty memory-backed stream open for reading and writing.
+    ///
+    /// If `limit` is not zero, the stream will not grow beyond `limit` bytes.
+    #[inline]
+    pub fn memory(limit: usize) -> Result<Self> {
+        let mut stream = unsafe { Self::from_result(ffi::gpgrt_fopenmem(limit, c"w+b".as_ptr()))? };
+        stream.memory = true;
+        Ok(stream)
+    }
+
+    /// Creates a new memory-backed stream open for reading and writing,
+    /// initialized with a copy of `data` and positioned at its start.
+    #[inline]
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut stream = unsafe {
+            Self::from_result(ffi::gpgrt_fopenmem_init(
+                0,
+                c"r+b".as_ptr(),
+                data.as_ptr().cast(),
+                data.len(),
+            ))?
+        };
+        stream.memory = true;
+        Ok(stream)
+    }
+
+    /// Creates a stream for the file descriptor `fd`.
+    ///
+    /// The file descriptor is closed together with the stream.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file descriptor that is not closed elsewhere.
+    #[inline]
+    pub unsafe fn from_fd(fd: i32, mode: &str) -> Result<Self> {
+        let mode = CString::new(mode)?;
+        Self::from_result(ffi::gpgrt_fdopen(fd, mode.as_ptr()))
+    }
+
+    /// Creates a stream that reads from, writes to and seeks within `inner`.
+    ///
+    /// `inner` is dropped when the stream is closed.
+    #[inline]
+    pub fn from_io<T>(inner: T, mode: &str) -> Result<Self>
+    where
+        T: Read + Write + Seek + Send + 'static,
+    {
+        Self::from_cookie(
+            inner,
+            mode,
+            ffi::gpgrt_cookie_io_functions_t {
+                func_read: Some(cookie_read::<T>),
+                func_write: Some(cookie_write::<T>),
+                func_seek: Some(cookie_seek::<T>),
+                func_close: Some(cookie_close_flush::<T>),
+            },
+        )
+    }
+
+    /// Creates a read-only stream backed by `inner`.
+    ///
+    /// `inner` is dropped when the stream is closed.
+    #[inline]
+    pub fn from_reader<R>(inner: R) -> Result<Self>
+    where
+        R: Read + Send + 'static,
+    {
+        Self::from_cookie(
+            inner,
+            "r",
+            ffi::gpgrt_cookie_io_functions_t {
+                func_read: Some(cookie_read::<R>),
+                func_close: Some(cookie_close::<R>),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Creates a write-only stream backed by `inner`.
+    ///
+    /// `inner` is flushed and dropped when the stream is closed.
+    #[inline]
+    pub fn from_writer<W>(inner: W) -> Result<Self>
+    where
+        W: Write + Send + 'static,
+    {
+        Self::from_cookie(
+            inner,
+            "w",
+            ffi::gpgrt_cookie_io_functions_t {
+                func_write: Some(cookie_write::<W>),
+                func_close: Some(cookie_close_flush::<W>),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn from_cookie<T: Send + 'static>(
+        inner: T,
+        mode: &str,
+        funcs: ffi::gpgrt_cookie_io_functions_t,
+    ) -> Result<Self> {
+        let mode = CString::new(mode)?;
+        let cookie = Box::into_raw(Box::new(inner));
+        unsafe {
+            let raw = ffi::gpgrt_fopencookie(cookie.cast(), mode.as_ptr(), funcs);
+            if raw.is_null() {
+                let err = Error::last_os_error();
+                drop(Box::from_raw(cookie));
+                return Err(err);
+            }
+            Ok(Self::from_raw(raw))
+        }
+    }
+
+    /// Creates an anonymous temporary file.
+    #[inline]
+    pub fn temp() -> Result<Self> {
+        unsafe { Self::from_result(ffi::gpgrt_tmpfile()) }
+    }
+
+    /// Returns the file descriptor backing the stream, if any.
+    #[inline]
+    pub fn fd(&self) -> Option<i32> {
+        match unsafe { ffi::gpgrt_fileno(self.as_raw()) } {
+            -1 => None,
+            fd => Some(fd),
+        }
+    }
+
+    /// Returns the file name associated with the stream, if any.
+    #[inline]
+    pub fn file_name(&self) -> Option<&str> {
+        unsafe {
+            ffi::gpgrt_fname_get(self.as_raw())
+                .as_ref()
+                .and_then(|s| CStr::from_ptr(s).to_str().ok())
+        }
+    }
+
+    /// Truncates the stream to `len` bytes.
+    #[inline]
+    pub fn truncate(&mut self, len: u64) -> Result<()> {
+        self.discard_buffer()?;
+        let len = len.try_into()?;
+        unsafe {
+            if ffi::gpgrt_ftruncate(self.as_raw(), len) != 0 {
+                return Err(Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+
+    /// Flushes and closes the stream, reporting any error that occurs.
+    #[inline]
+    pub fn close(mut self) -> Result<()> {
+        self.discard_buffer()?;
+        let raw = self.as_raw();
+        mem::forget(self);
+        unsafe {
+            if ffi::gpgrt_fclose(raw) != 0 {
+                return Err(Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+
+    /// Closes a memory-backed stream and returns its full contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::EINVAL` if the stream was not created by
+    /// [`Stream::memory`] or [`Stream::from_bytes`]. The stream is closed in
+    /// that case too.
+    pub fn into_bytes(self) -> Result<Vec<u8>> {
+        if !self.memory {
+            return Err(Error::EINVAL);
+        }
+        let raw = self.into_raw();
+        let mut data = ptr::null_mut();
+        let mut len = 0;
+        unsafe {
+            // The stream is released even if closing it fails.
+            if ffi::gpgrt_fclose_snatch(raw, &mut data, &mut len) != 0 {
+                return Err(Error::last_os_error());
+            }
+            if data.is_null() {
+                return Ok(Vec::new());
+            }
+            let result = slice::from_raw_parts(data.cast::<u8>(), len).to_vec();
+            ffi::gpgrt_free(data);
+            Ok(result)
+        }
+    }
+
+    /// Hands back any unconsumed data read by `fill_buf` to the stream.
+    fn discard_buffer(&mut self) -> Result<()> {
+        let remaining = self.filled - self.pos;
+        self.pos = 0;
+        self.filled = 0;
+        if remaining > 0 {
+            self.raw_seek(-(remaining as i64), SEEK_CUR)?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::useless_conversion)]
+    fn raw_seek(&mut self, off: i64, whence: c_int) -> Result<u64> {
+        let off = off.try_into()?;
+        unsafe {
+            if ffi::gpgrt_fseeko(self.as_raw(), off, whence) != 0 {
+                return Err(Error::last_os_error());
+            }
+            match ffi::gpgrt_ftello(self.as_raw()) {
+                x if x < 0 => Err(Error::last_os_error()),
+                x => Ok(x as u64),
+            }
+        }
+    }
+
+    fn raw_read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut read = 0;
+        unsafe {
+            if ffi::gpgrt_read(self.as_raw(), buf.as_mut_ptr().cast(), buf.len(), &mut read) != 0 {
+                return Err(Error::last_os_error());
+            }
+        }
+        Ok(read)
+    }
+}
+
+/// Runs a cookie callback, translating errors and panics into `errno`
+/// values so they can be reported to estream.
+fn cookie_call<R>(err_value: R, f: impl FnOnce() -> io::Result<R>) -> R {
+    let err = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(r)) => return r,
+        Ok(Err(e)) => Error::from(e),
+        Err(_) => Error::EIO,
+    };
+    let errno = match err.to_errno() {
+        0 => Error::EIO.to_errno(),
+        x => x,
+    };
+    unsafe {
+        ffi::gpg_err_set_errno(errno);
+    }
+    err_value
+}
+
+unsafe extern "C" fn cookie_read<R: Read>(
+    cookie: *mut c_void,
+    buf: *mut c_void,
+    size: usize,
+) -> ffi::gpgrt_ssize_t {
+    cookie_call(-1, || {
+        let inner = &mut *cookie.cast::<R>();
+        let buf = slice::from_raw_parts_mut(buf.cast::<u8>(), size);
+        Ok(inner.read(buf)? as ffi::gpgrt_ssize_t)
+    })
+}
+
+unsafe extern "C" fn cookie_write<W: Write>(
+    cookie: *mut c_void,
+    buf: *const c_void,
+    size: usize,
+) -> ffi::gpgrt_ssize_t {
+    cookie_call(-1, || {
+        let inner = &mut *cookie.cast::<W>();
+        // estream signals a flush by writing zero bytes from a null buffer.
+        if buf.is_null() && size == 0 {
+            inner.flush()?;
+            return Ok(0);
+        }
+        let buf = slice::from_raw_parts(buf.cast::<u8>(), size);
+        Ok(inner.write(buf)? as ffi::gpgrt_ssize_t)
+    })
+}
+
+#[allow(clippy::useless_conversion)]
+unsafe extern "C" fn cookie_seek<S: Seek>(
+    cookie: *mut c_void,
+    pos: *mut ffi::gpgrt_off_t,
+    whence: c_int,
+) -> c_int {
+    cookie_call(-1, || {
+        let inner = &mut *cookie.cast::<S>();
+        let pos = &mut *pos;
+        let target = match whence {
+            SEEK_SET => SeekFrom::Start(u64::try_from(*pos).map_err(Error::from)?),
+            SEEK_CUR => SeekFrom::Current((*pos).into()),
+            SEEK_END => SeekFrom::End((*pos).into()),
+            _ => return Err(Error::EINVAL.into()),
+        };
+        *pos = inner
+            .seek(target)?
+            .try_into()
+            .map_err(|_| Error::EOVERFLOW)?;
+        Ok(0)
+    })
+}
+
+unsafe extern "C" fn cookie_close<T>(cookie: *mut c_void) -> c_int {
+    cookie_call(-1, || {
+        drop(Box::from_raw(cookie.cast::<T>()));
+        Ok(0)
+    })
+}
+
+unsafe extern "C" fn cookie_close_flush<W: Write>(cookie: *mut c_void) -> c_int {
+    cookie_call(-1, || {
+        let mut inner = Box::from_raw(cookie.cast::<W>());
+        inner.flush()?;
+        Ok(0)
+    })
+}
+
+impl fmt::Debug for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stream")
+            .field("raw", &self.raw)
+            .field("file_name", &self.file_name())
+            .finish()
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.filled {
+            return Ok(self.raw_read(buf)?);
+        }
+        let n = {
+            let mut rem = self.fill_buf()?;
+            rem.read(buf)?
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for Stream {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.filled {
+            if self.buf.is_empty() {
+                self.buf = vec![0; BUF_SIZE].into_boxed_slice();
+            }
+            let mut buf = mem::take(&mut self.buf);
+            let result = self.raw_read(&mut buf);
+            self.buf = buf;
+            self.filled = result?;
+            self.pos = 0;
+        }
+        Ok(&self.buf[self.pos..self.filled])
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.filled);
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.discard_buffer()?;
+        let mut written = 0;
+        unsafe {
+            if ffi::gpgrt_write(self.as_raw(), buf.as_ptr().cast(), buf.len(), &mut written) != 0 {
+                return Err(Error::last_os_error().into());
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        unsafe {
+            if ffi::gpgrt_fflush(self.as_raw()) != 0 {
+                return Err(Error::last_os_error().into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Seek for Stream {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let remaining = (self.filled - self.pos) as i64;
+        let (off, whence) = match pos {
+            SeekFrom::Start(off) => (off.try_into().map_err(Error::from)?, SEEK_SET),
+            SeekFrom::End(off) => (off, SEEK_END),
+            SeekFrom::Current(off) => (off - remaining, SEEK_CUR),
+        };
+        self.pos = 0;
+        self.filled = 0;
+        Ok(self.raw_seek(off, whence)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, BufRead, Cursor, Read, Seek, SeekFrom, Write},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use super::Stream;
+    use crate::Error;
+
+    #[test]
+    fn test_memory() {
+        let mut s = Stream::memory(0).unwrap();
+        s.write_all(b"hello\nworld\n").unwrap();
+        s.rewind().unwrap();
+        let mut line = String::new();
+        s.read_line(&mut line).unwrap();
+        assert_eq!(line, "hello\n");
+        s.stream_position().unwrap();
+        s.write_all(b"WORLD").unwrap();
+        s.rewind().unwrap();
+        let mut all = String::new();
+        s.read_to_string(&mut all).unwrap();
+        assert_eq!(all, "hello\nWORLD\n");
+        assert_eq!(s.into_bytes().unwrap(), b"hello\nWORLD\n");
+        assert_eq!(Stream::memory(0).unwrap().into_bytes().unwrap(), b"");
+    }
+
+    #[test]
+    fn test_cookie() {
+        let mut s = Stream::from_io(Cursor::new(Vec::new()), "w+").unwrap();
+        s.write_all(b"hello world").unwrap();
+        s.seek(SeekFrom::Start(6)).unwrap();
+        let mut rest = String::new();
+        s.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "world");
+
+        struct Tracked(Cursor<Vec<u8>>, Arc<AtomicBool>);
+        impl Read for Tracked {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.1.store(true, Ordering::SeqCst);
+            }
+        }
+        let dropped = Arc::new(AtomicBool::new(false));
+        let s = Stream::from_reader(Tracked(Cursor::default(), dropped.clone())).unwrap();
+        assert_eq!(s.into_bytes().unwrap_err(), Error::EINVAL);
+        assert!(dropped.load(Ordering::SeqCst));
+
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::ConnectionReset.into())
+            }
+        }
+        let mut s = Stream::from_reader(Failing).unwrap();
+        let err = s.read(&mut [0; 4]).unwrap_err();
+        assert_eq!(Error::from(err), Error::ECONNRESET);
+
+        struct Panicking;
+        impl Read for Panicking {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                panic!("boom");
+            }
+        }
+        let mut s = Stream::from_reader(Panicking).unwrap();
+        let err = s.read(&mut [0; 4]).unwrap_err();
+        assert_eq!(Error::from(err), Error::EIO);
+    }
+}
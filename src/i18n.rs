@@ -0,0 +1,183 @@
+use alloc::borrow::Cow;
+use core::{
+    fmt, hint,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use crate::{Error, Result};
+
+static INIT_LOCK: AtomicBool = AtomicBool::new(false);
+static INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs `f` while holding the lock that serializes `gpg_err_init` and
+/// `gpg_err_deinit` with updates of `INIT_COUNT`.
+fn with_init_lock<R>(f: impl FnOnce() -> R) -> R {
+    while INIT_LOCK
+        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        hint::spin_loop();
+    }
+    let result = f();
+    INIT_LOCK.store(false, Ordering::Release);
+    result
+}
+
+/// Initializes libgpg-error, binding its translations to the locale
+/// directory the library was installed with.
+///
+/// When the last guard returned by this function is dropped, the resources
+/// libgpg-error allocated for the calling thread are released. The library
+/// itself stays initialized, so descriptions remain available afterwards.
+///
+/// # Errors
+///
+/// Returns the error reported by `gpg_err_init`.
+pub fn init() -> Result<InitGuard> {
+    with_init_lock(|| match unsafe { ffi::gpg_err_init() } {
+        ffi::GPG_ERR_NO_ERROR => {
+            INIT_COUNT.fetch_add(1, Ordering::Relaxed);
+            Ok(InitGuard { _priv: () })
+        }
+        err => Err(Error::new(err)),
+    })
+}
+
+/// A guard keeping libgpg-error initialized, returned by [`init`].
+#[must_use = "if unused the resources of libgpg-error will immediately be released"]
+pub struct InitGuard {
+    _priv: (),
+}
+
+impl Drop for InitGuard {
+    fn drop(&mut self) {
+        with_init_lock(|| {
+            if INIT_COUNT.fetch_sub(1, Ordering::Relaxed) == 1 {
+                // Mode 0 would deinitialize the library for the whole
+                // process, including callers that did not use `init`.
+                unsafe {
+                    ffi::gpg_err_deinit(1);
+                }
+            }
+        });
+    }
+}
+
+impl fmt::Debug for InitGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitGuard").finish_non_exhaustive()
+    }
+}
+
+impl Error {
+    /// Returns a description of the error translated to `lang`, e.g. `de`
+    /// or `pt_BR`, instead of the language of the process' locale.
+    ///
+    /// Only UTF-8 locales are used, so `lang` may name the UTF-8 codeset
+    /// (e.g. `de_DE.UTF-8`) but no other. Falls back to English if no such
+    /// locale is installed or if libgpg-error has no translation for `lang`.
+    /// Descriptions are only translated on Unix systems, and never with the
+    /// `pure_rust` feature.
+    pub fn description_in(&self, lang: &str) -> Cow<'static, str> {
+        imp::with_messages_locale(Some(lang), || self.description())
+    }
+
+    /// Returns the untranslated, English description of the error,
+    /// regardless of the process' locale.
+    #[inline]
+    pub fn description_c(&self) -> Cow<'static, str> {
+        imp::with_messages_locale(None, || self.description())
+    }
+}
+
+#[cfg(all(unix, not(feature = "pure_rust")))]
+mod imp {
+    use alloc::{ffi::CString, format, vec::Vec};
+    use core::ptr;
+
+    /// Runs `f` with the messages of the calling thread using the UTF-8
+    /// locale for `lang`, or the "C" locale if there is none.
+    pub fn with_messages_locale<R>(lang: Option<&str>, f: impl FnOnce() -> R) -> R {
+        const MASK: libc::c_int = libc::LC_MESSAGES_MASK | libc::LC_CTYPE_MASK;
+
+        let mut candidates = Vec::new();
+        if let Some(lang) = lang.filter(|l| !l.is_empty()) {
+            // Other codesets would transliterate translations to ASCII or
+            // produce descriptions that are not valid UTF-8.
+            let (name, modifier) = match lang.find('@') {
+                Some(pos) => lang.split_at(pos),
+                None => (lang, ""),
+            };
+            match name.split_once('.') {
+                Some((_, codeset))
+                    if codeset.eq_ignore_ascii_case("UTF-8")
+                        || codeset.eq_ignore_ascii_case("utf8") =>
+                {
+                    candidates.push(lang.into());
+                }
+                Some(_) => {}
+                None => {
+                    candidates.push(format!("{name}.UTF-8{modifier}"));
+                    if !name.contains('_') {
+                        let country = name.to_ascii_uppercase();
+                        candidates.push(format!("{name}_{country}.UTF-8{modifier}"));
+                    }
+                }
+            }
+        }
+        candidates.push("C".into());
+
+        let locale = candidates
+            .into_iter()
+            .filter_map(|name| CString::new(name).ok())
+            .map(|name| unsafe { libc::newlocale(MASK, name.as_ptr(), ptr::null_mut()) })
+            .find(|l| !l.is_null());
+        let Some(locale) = locale else {
+            return f();
+        };
+        unsafe {
+            let previous = libc::uselocale(locale);
+            let result = f();
+            libc::uselocale(previous);
+            libc::freelocale(locale);
+            result
+        }
+    }
+}
+
+#[cfg(not(all(unix, not(feature = "pure_rust"))))]
+mod imp {
+    #[inline]
+    pub fn with_messages_locale<R>(_lang: Option<&str>, f: impl FnOnce() -> R) -> R {
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init() {
+        let count = || INIT_COUNT.load(Ordering::Relaxed);
+        let base = count();
+        let a = init().unwrap();
+        let b = init().unwrap();
+        assert_eq!(count(), base + 2);
+        drop(a);
+        assert_eq!(count(), base + 1);
+        assert_eq!(Error::NO_PUBKEY.description_c(), "No public key");
+        drop(b);
+        assert_eq!(count(), base);
+    }
+
+    #[test]
+    fn test_description_c() {
+        let err = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        assert_eq!(err.description_c(), "No public key");
+        assert_eq!(err.description_in("xx"), "No public key");
+        assert_eq!(err.description_in("C"), "No public key");
+        assert_eq!(err.description_in("de\0"), "No public key");
+        assert_eq!(err.description_in("C.ISO-8859-1"), "No public key");
+    }
+}
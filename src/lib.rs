@@ -1,17 +1,73 @@
-use std::{
-    borrow::Cow,
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+};
+use core::{
     convert::Infallible,
     error,
-    ffi::{CStr, NulError},
+    ffi::{c_int, CStr},
     fmt::{self, Write},
-    io::{self, ErrorKind},
     num::TryFromIntError,
-    os::raw::c_int,
     result, str,
 };
+#[cfg(feature = "std")]
+use std::{
+    ffi::NulError,
+    io::{self, ErrorKind},
+};
+
+#[cfg(gpg_error_1_41)]
+pub use self::version::Version;
+pub use self::{
+    category::ErrorCategory,
+    code::{ErrorCode, UnknownCode},
+    i18n::{init, InitGuard},
+    severity::Severity,
+    source::ErrorSource,
+    version::{check_version, version},
+};
+#[cfg(feature = "std")]
+pub use self::{
+    parse_error::ParseError,
+    report::{Context, Report},
+    retry::{retry, retry_with_clock, Backoff, Clock, SystemClock},
+};
 
-pub type ErrorSource = ffi::gpg_err_source_t;
-pub type ErrorCode = ffi::gpg_err_code_t;
+#[cfg(all(gpg_error_1_41, feature = "std", not(feature = "pure_rust")))]
+pub mod argparse;
+#[cfg(all(feature = "std", not(feature = "pure_rust")))]
+pub mod base64;
+mod category;
+mod code;
+#[cfg(feature = "std")]
+pub mod conffile;
+#[cfg(all(feature = "std", not(feature = "pure_rust")))]
+pub mod estream;
+mod i18n;
+#[cfg(all(feature = "std", not(feature = "pure_rust")))]
+pub mod log;
+#[cfg(feature = "std")]
+pub mod namevalue;
+#[cfg(all(gpg_error_1_49, feature = "std", not(feature = "pure_rust")))]
+pub mod nvc;
+#[cfg(feature = "std")]
+mod parse_error;
+#[cfg(all(gpg_error_1_48, feature = "std", not(feature = "pure_rust")))]
+pub mod process;
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod severity;
+mod source;
+#[cfg(all(gpgrt_lock, feature = "std", not(feature = "pure_rust")))]
+pub mod sync;
+mod version;
 
 /// A type wrapping errors produced by GPG libraries.
 #[repr(transparent)]
@@ -36,7 +92,7 @@ impl Error {
     /// Creates a new error from an error source and an error code.
     #[inline]
     pub fn from_source(source: ErrorSource, code: ErrorCode) -> Self {
-        Error::new(ffi::gpg_err_make(source, code))
+        Error::new(ffi::gpg_err_make(source.raw(), code.raw()))
     }
 
     /// Creates a new error from an error code using the default
@@ -61,15 +117,46 @@ impl Error {
     /// Returns the OS error that this error represents.
     #[inline]
     pub fn to_errno(&self) -> i32 {
-        unsafe { ffi::gpg_err_code_to_errno(self.code()) }
+        unsafe { ffi::gpg_err_code_to_errno(self.raw_code()) }
     }
 
     /// Returns the error code.
     #[inline]
     pub const fn code(&self) -> ErrorCode {
+        ErrorCode::from_raw(self.raw_code())
+    }
+
+    /// Returns the symbolic name of the error code (e.g. `GPG_ERR_NO_PUBKEY`),
+    /// if it is known.
+    #[inline]
+    pub const fn code_name(&self) -> Option<&'static str> {
+        self.code().name()
+    }
+
+    /// Creates a new error from the symbolic name of an error code using
+    /// the default error source `SOURCE_UNKNOWN`.
+    ///
+    /// See [`ErrorCode::from_name`] for the accepted forms.
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        ErrorCode::from_name(name).map(Self::from_code)
+    }
+
+    /// Returns the raw error code value.
+    #[inline]
+    pub const fn raw_code(&self) -> ffi::gpg_err_code_t {
         ffi::gpg_err_code(self.0)
     }
 
+    /// Returns the error source.
+    #[inline]
+    pub const fn error_source(&self) -> ErrorSource {
+        match ErrorSource::new(ffi::gpg_err_source(self.0)) {
+            Some(s) => s,
+            None => unreachable!(),
+        }
+    }
+
     /// Returns a description of the source of the error as a UTF-8 string.
     #[inline]
     pub fn source(&self) -> Option<&'static str> {
@@ -120,6 +207,7 @@ impl Error {
     /// Returns an error if the provided buffer is not long enough or
     /// if the error is not recognized.
     #[inline]
+    #[allow(clippy::result_unit_err)]
     pub fn write_description<'r>(&self, buf: &'r mut [u8]) -> result::Result<&'r mut [u8], ()> {
         let p = buf.as_mut_ptr();
         unsafe {
@@ -206,7 +294,7 @@ impl fmt::Display for Error {
             .write_description(&mut buf)
             .map(|x| &*x)
             .unwrap_or(b"Unknown error");
-        write!(fmt, "{} (gpg error {})", Escaped(desc), self.code())
+        write!(fmt, "{} (gpg error {})", Escaped(desc), self.raw_code())
     }
 }
 
@@ -217,6 +305,7 @@ impl From<Infallible> for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<NulError> for Error {
     #[inline]
     fn from(_: NulError) -> Self {
@@ -231,13 +320,17 @@ impl From<TryFromIntError> for Error {
     }
 }
 
-impl From<io::Error> for Error {
-    fn from(err: io::Error) -> Self {
-        let kind = err.kind();
-        if let Some(Ok(err)) = err.into_inner().map(|e| e.downcast::<Self>()) {
+#[cfg(feature = "std")]
+impl Error {
+    /// Returns the error wrapped by `err` or the closest match for its kind.
+    fn from_io_error(err: &io::Error) -> Self {
+        let inner = err.get_ref();
+        if let Some(err) = inner.and_then(|e| e.downcast_ref::<Self>()) {
             *err
+        } else if let Some(report) = inner.and_then(|e| e.downcast_ref::<Report>()) {
+            report.error()
         } else {
-            match kind {
+            match err.kind() {
                 ErrorKind::AddrInUse => Self::EADDRINUSE,
                 ErrorKind::AddrNotAvailable => Self::EADDRNOTAVAIL,
                 ErrorKind::AlreadyExists => Self::EEXIST,
@@ -260,6 +353,15 @@ impl From<io::Error> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        Self::from_io_error(&err)
+    }
+}
+
+#[cfg(feature = "std")]
 impl From<Error> for io::Error {
     fn from(err: Error) -> Self {
         let kind = match err.with_source(Error::SOURCE_UNKNOWN) {
@@ -312,13 +414,13 @@ macro_rules! return_err {
 
 #[cfg(test)]
 mod tests {
-    use super::Error;
+    use super::{Error, ErrorCode, ErrorSource};
 
     #[test]
     fn test_errno() {
         let e = Error::from_errno(0);
         assert_eq!(e.to_errno(), 0);
-        assert_eq!(e.code(), 0);
+        assert_eq!(e.code(), ErrorCode::NO_ERROR);
         assert_eq!(e, Error::NO_ERROR);
     }
 
@@ -331,4 +433,57 @@ mod tests {
         assert_eq!(e.to_errno(), 0);
         assert_eq!(e, Error::MISSING_ERRNO);
     }
+
+    #[test]
+    fn test_description() {
+        let e = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        assert_eq!(e.description(), "No public key");
+        assert_eq!(e.source(), Some("GPGME"));
+        assert_eq!(e.write_description(&mut [0; 4]), Err(()));
+        assert_eq!(Error::from_errno(Error::EPIPE.to_errno()), Error::EPIPE);
+    }
+
+    #[test]
+    fn test_code() {
+        let e = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        assert!(matches!(e.code(), ErrorCode::NO_PUBKEY));
+        assert_eq!(Error::from(ErrorCode::EPIPE), Error::EPIPE);
+        assert!(matches!(ErrorCode::try_from(1023), Ok(ErrorCode::Unknown(c)) if c.raw() == 1023));
+        assert!(matches!(
+            ErrorCode::from_raw(9 | 1 << 16),
+            ErrorCode::NO_PUBKEY
+        ));
+        assert_eq!(
+            ErrorCode::try_from(ffi::GPG_ERR_CODE_DIM),
+            Err(Error::EINVAL)
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        let e = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        assert_eq!(e.error_source(), ErrorSource::GPGME);
+        assert_eq!(e.error_source().name(), Some("GPG_ERR_SOURCE_GPGME"));
+        assert_eq!("gpgme".parse(), Ok(ErrorSource::GPGME));
+        assert_eq!("GPG_ERR_SOURCE_GPGME".parse(), Ok(ErrorSource::GPGME));
+        assert_eq!("bogus".parse::<ErrorSource>(), Err(Error::INV_NAME));
+        assert_eq!(ErrorSource::try_from(200), Err(Error::EINVAL));
+        assert!(ErrorSource::iter().any(|s| s == ErrorSource::USER_4));
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Error::NO_PUBKEY.code_name(), Some("GPG_ERR_NO_PUBKEY"));
+        assert_eq!(Error::EPIPE.code_name(), Some("GPG_ERR_EPIPE"));
+        assert_eq!(
+            Error::from_code(ErrorCode::from_raw(1023)).code_name(),
+            None
+        );
+        assert_eq!(
+            Error::from_name("GPG_ERR_NO_PUBKEY"),
+            Some(Error::NO_PUBKEY)
+        );
+        assert_eq!(Error::from_name("EPIPE"), Some(Error::EPIPE));
+        assert_eq!(Error::from_name("GPG_ERR_BOGUS"), None);
+    }
 }
@@ -0,0 +1,391 @@
+//! Safe wrappers for libgpg-error's logging subsystem.
+//!
+//! Messages logged here share the prefix, sink and error counter used by
+//! GnuPG components running in the same process.
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    ops::{BitOr, BitOrAssign},
+    os::raw::{c_int, c_uint},
+    path::Path,
+    ptr,
+};
+
+use crate::{estream::Stream, Error, Result};
+
+#[cfg(feature = "tracing")]
+pub use self::layer::Layer;
+#[cfg(feature = "log")]
+pub use self::logger::Logger;
+
+#[cfg(feature = "tracing")]
+mod layer;
+#[cfg(feature = "log")]
+mod logger;
+
+/// Flags controlling the prefix of log lines.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct LogFlags(c_uint);
+
+impl LogFlags {
+    /// Include the prefix text.
+    pub const WITH_PREFIX: Self = Self(ffi::GPGRT_LOG_WITH_PREFIX);
+    /// Include a timestamp.
+    pub const WITH_TIME: Self = Self(ffi::GPGRT_LOG_WITH_TIME);
+    /// Include the process id.
+    pub const WITH_PID: Self = Self(ffi::GPGRT_LOG_WITH_PID);
+    /// The process is running detached and has no terminal to fall back on.
+    pub const RUN_DETACHED: Self = Self(ffi::GPGRT_LOG_RUN_DETACHED);
+    /// Do not consult the Windows registry for the log destination.
+    pub const NO_REGISTRY: Self = Self(ffi::GPGRT_LOG_NO_REGISTRY);
+
+    /// Returns the raw flag bits.
+    #[inline]
+    pub const fn bits(&self) -> c_uint {
+        self.0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for LogFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for LogFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The severity of a log message.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    #[inline]
+    fn raw(self) -> c_int {
+        match self {
+            Self::Debug => ffi::GPGRT_LOGLVL_DEBUG,
+            Self::Info => ffi::GPGRT_LOGLVL_INFO,
+            Self::Warn => ffi::GPGRT_LOGLVL_WARN,
+            Self::Error => ffi::GPGRT_LOGLVL_ERROR,
+        }
+    }
+}
+
+/// Sets the prefix text and flags used for every log line.
+///
+/// # Safety
+///
+/// libgpg-error does not synchronize its logging configuration. This must
+/// not be called while any other thread may be logging.
+pub unsafe fn set_prefix(text: &str, flags: LogFlags) -> Result<()> {
+    let text = CString::new(text)?;
+    ffi::gpgrt_log_set_prefix(text.as_ptr(), flags.bits());
+    Ok(())
+}
+
+/// Returns the current prefix text and flags.
+pub fn prefix() -> (Option<String>, LogFlags) {
+    let mut flags = 0;
+    unsafe {
+        let text = ffi::gpgrt_log_get_prefix(&mut flags)
+            .as_ref()
+            .map(|s| CStr::from_ptr(s).to_string_lossy().into_owned());
+        (text, LogFlags(flags))
+    }
+}
+
+/// Directs log output to the file at `path`, which is opened for appending.
+///
+/// # Safety
+///
+/// See [`set_prefix`].
+pub unsafe fn set_file(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref().to_str().ok_or(Error::EINVAL)?;
+    set_sink_name(path)
+}
+
+/// Directs log output to the local (Unix domain) socket at `path`.
+///
+/// # Safety
+///
+/// See [`set_prefix`].
+pub unsafe fn set_socket(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref().to_str().ok_or(Error::EINVAL)?;
+    set_sink_name(&format!("socket://{path}"))
+}
+
+unsafe fn set_sink_name(name: &str) -> Result<()> {
+    let name = CString::new(name)?;
+    ffi::gpgrt_log_set_sink(name.as_ptr(), ptr::null_mut(), -1);
+    Ok(())
+}
+
+/// Directs log output to the file descriptor `fd`.
+///
+/// # Safety
+///
+/// See [`set_prefix`]. Additionally, `fd` must not be closed elsewhere, as
+/// libgpg-error closes it when log output is directed elsewhere, e.g. by the
+/// next call to one of the `set_*` functions.
+#[inline]
+pub unsafe fn set_fd(fd: i32) {
+    ffi::gpgrt_log_set_sink(ptr::null(), ptr::null_mut(), fd);
+}
+
+/// Directs log output to `stream`.
+///
+/// The stream is closed by libgpg-error when log output is directed
+/// elsewhere, e.g. by the next call to one of the `set_*` functions.
+///
+/// # Safety
+///
+/// See [`set_prefix`].
+#[inline]
+pub unsafe fn set_stream(stream: Stream) {
+    ffi::gpgrt_log_set_sink(ptr::null(), stream.into_raw(), -1);
+}
+
+/// Returns the file descriptor currently used for logging, if any.
+#[inline]
+pub fn fd() -> Option<i32> {
+    match unsafe { ffi::gpgrt_log_get_fd() } {
+        -1 => None,
+        fd => Some(fd),
+    }
+}
+
+/// Logs a message with the given level.
+///
+/// Messages logged at [`Level::Error`] increment the error counter.
+#[inline]
+pub fn log(level: Level, msg: &str) {
+    let len = msg.len().min(c_int::MAX as usize) as c_int;
+    unsafe {
+        match level {
+            Level::Error => ffi::gpgrt_log_error(c"%.*s\n".as_ptr(), len, msg.as_ptr()),
+            _ => ffi::gpgrt_log(level.raw(), c"%.*s\n".as_ptr(), len, msg.as_ptr()),
+        }
+    }
+}
+
+/// Logs an informational message.
+#[inline]
+pub fn info(msg: &str) {
+    log(Level::Info, msg);
+}
+
+/// Logs a warning.
+#[inline]
+pub fn warn(msg: &str) {
+    log(Level::Warn, msg);
+}
+
+/// Logs an error and increments the error counter.
+#[inline]
+pub fn error(msg: &str) {
+    log(Level::Error, msg);
+}
+
+/// Logs a debug message.
+#[inline]
+pub fn debug(msg: &str) {
+    log(Level::Debug, msg);
+}
+
+/// Logs a fatal error and terminates the process.
+pub fn fatal(msg: &str) -> ! {
+    let len = msg.len().min(c_int::MAX as usize) as c_int;
+    unsafe { ffi::gpgrt_log_fatal(c"%.*s\n".as_ptr(), len, msg.as_ptr()) }
+}
+
+/// Logs a message describing a bug and aborts the process.
+pub fn bug(msg: &str) -> ! {
+    let len = msg.len().min(c_int::MAX as usize) as c_int;
+    unsafe { ffi::gpgrt_log_bug(c"%.*s\n".as_ptr(), len, msg.as_ptr()) }
+}
+
+/// Logs `data` as a hex dump preceded by `text`.
+#[inline]
+pub fn hexdump(text: &str, data: &[u8]) {
+    let len = text.len().min(c_int::MAX as usize) as c_int;
+    unsafe {
+        ffi::gpgrt_log_printhex(
+            data.as_ptr().cast(),
+            data.len(),
+            c"%.*s".as_ptr(),
+            len,
+            text.as_ptr(),
+        );
+    }
+}
+
+/// Flushes any buffered log output.
+#[inline]
+pub fn flush() {
+    unsafe {
+        ffi::gpgrt_log_flush();
+    }
+}
+
+/// Returns the number of errors logged so far.
+#[inline]
+pub fn error_count() -> u32 {
+    unsafe { ffi::gpgrt_get_errorcount(0) as u32 }
+}
+
+/// Resets the error counter and returns its previous value.
+#[inline]
+pub fn reset_error_count() -> u32 {
+    unsafe { ffi::gpgrt_get_errorcount(1) as u32 }
+}
+
+/// Increments the error counter without logging a message.
+#[inline]
+pub fn inc_error_count() {
+    unsafe {
+        ffi::gpgrt_inc_errorcount();
+    }
+}
+
+/// Renders an error as its symbolic code followed by its source,
+/// e.g. `GPG_ERR_NO_PUBKEY <GPGME>`.
+#[cfg_attr(not(any(feature = "log", feature = "tracing")), allow(dead_code))]
+struct ErrorField<'a>(&'a Error);
+
+impl fmt::Display for ErrorField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.code_name() {
+            Some(name) => f.write_str(name)?,
+            None => write!(f, "{}", self.0.raw_code())?,
+        }
+        match self.0.source() {
+            Some(source) => write!(f, " <{source}>"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    /// Serializes the tests that log, as the sink is shared.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Directs log output back to libgpg-error's stderr stream. Unlike
+    /// `set_fd(2)`, this does not leave fd 2 to be closed by the next sink.
+    fn reset_sink() {
+        unsafe {
+            ffi::gpgrt_log_set_sink(ptr::null(), ptr::null_mut(), -1);
+        }
+    }
+
+    #[test]
+    fn test_stream_sink() {
+        let _lock = LOCK.lock().unwrap();
+        let buf = SharedBuf::default();
+        unsafe {
+            set_stream(Stream::from_writer(buf.clone()).unwrap());
+        }
+        info("logged to memory");
+        warn("second line");
+        // Replacing the sink closes and flushes the stream.
+        reset_sink();
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("logged to memory\n"), "{output:?}");
+        assert!(output.contains("second line\n"), "{output:?}");
+        assert_eq!(Arc::strong_count(&buf.0), 1);
+    }
+
+    #[test]
+    fn test_error_count() {
+        let _lock = LOCK.lock().unwrap();
+        let before = error_count();
+        error("test error");
+        inc_error_count();
+        assert!(error_count() >= before + 2);
+        assert!((LogFlags::WITH_PID | LogFlags::WITH_TIME).contains(LogFlags::WITH_TIME));
+    }
+
+    #[test]
+    fn test_error_field() {
+        let e = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        assert_eq!(ErrorField(&e).to_string(), "GPG_ERR_NO_PUBKEY <GPGME>");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_logger() {
+        let _lock = LOCK.lock().unwrap();
+        let buf = SharedBuf::default();
+        unsafe {
+            set_stream(Stream::from_writer(buf.clone()).unwrap());
+        }
+        Logger::new()
+            .with_max_level(log::LevelFilter::Info)
+            .install()
+            .unwrap();
+        let err = Error::NO_PUBKEY.with_source(Error::SOURCE_GPGME);
+        log::info!(error:err = err, attempt = 2; "lookup failed");
+        log::debug!("not logged");
+        reset_sink();
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("lookup failed error=GPG_ERR_NO_PUBKEY <GPGME> attempt=2\n"),
+            "{output:?}"
+        );
+        assert!(!output.contains("not logged"), "{output:?}");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_layer() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let _lock = LOCK.lock().unwrap();
+        let subscriber = tracing_subscriber::registry().with(Layer::new());
+        tracing::subscriber::with_default(subscriber, || {
+            let before = error_count();
+            let err = Error::NO_PUBKEY;
+            tracing::error!(error = &err as &dyn std::error::Error, "lookup failed");
+            assert!(error_count() > before);
+        });
+    }
+}
@@ -0,0 +1,76 @@
+use std::{error::Error as StdError, fmt, fmt::Write};
+
+use tracing_core::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_subscriber::layer::{Context, Layer as LayerTrait};
+
+use super::{ErrorField, Level};
+use crate::Error;
+
+/// A [`tracing_subscriber::Layer`] that writes events through libgpg-error's
+/// logger.
+///
+/// Events at the `ERROR` level increment the gpgrt error counter. Fields
+/// holding an [`Error`] are rendered with the symbolic error code and source.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Layer {
+    _priv: (),
+}
+
+impl Layer {
+    /// Creates a new layer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { _priv: () }
+    }
+}
+
+#[derive(Default)]
+struct Fields {
+    message: String,
+    rest: String,
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.rest, " {}={value}", field.name());
+        }
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn StdError + 'static)) {
+        match value.downcast_ref::<Error>() {
+            Some(err) => {
+                let _ = write!(self.rest, " {}={}", field.name(), ErrorField(err));
+            }
+            None => self.record_debug(field, &format_args!("{value}")),
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.rest, " {}={value:?}", field.name());
+        }
+    }
+}
+
+impl<S: Subscriber> LayerTrait<S> for Layer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let level = match *event.metadata().level() {
+            tracing_core::Level::ERROR => Level::Error,
+            tracing_core::Level::WARN => Level::Warn,
+            tracing_core::Level::INFO => Level::Info,
+            _ => Level::Debug,
+        };
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        fields.message.push_str(&fields.rest);
+        super::log(level, &fields.message);
+    }
+}
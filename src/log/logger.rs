@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use log::{
+    kv::{self, VisitSource},
+    LevelFilter, Log, Metadata, Record, SetLoggerError,
+};
+
+use super::{ErrorField, Level};
+use crate::Error;
+
+/// An implementation of [`log::Log`] that writes records through
+/// libgpg-error's logger.
+///
+/// Records at the `Error` level increment the gpgrt error counter.
+/// Key-value pairs holding an [`Error`] are rendered with the symbolic error
+/// code and source.
+#[derive(Debug, Copy, Clone)]
+pub struct Logger {
+    max_level: LevelFilter,
+}
+
+impl Default for Logger {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    /// Creates a logger that accepts records of every level.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            max_level: LevelFilter::Trace,
+        }
+    }
+
+    /// Sets the most verbose level that is logged.
+    #[inline]
+    pub const fn with_max_level(self, max_level: LevelFilter) -> Self {
+        Self { max_level }
+    }
+
+    /// Installs the logger as the global logger for the `log` crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a global logger has already been installed.
+    pub fn install(self) -> Result<(), SetLoggerError> {
+        log::set_logger(Box::leak(Box::new(self)))?;
+        log::set_max_level(self.max_level);
+        Ok(())
+    }
+}
+
+struct Fields<'a>(&'a mut String);
+
+impl<'kvs> VisitSource<'kvs> for Fields<'_> {
+    fn visit_pair(&mut self, key: kv::Key<'kvs>, value: kv::Value<'kvs>) -> Result<(), kv::Error> {
+        let _ = match value
+            .to_borrowed_error()
+            .and_then(|e| e.downcast_ref::<Error>())
+        {
+            Some(err) => write!(self.0, " {key}={}", ErrorField(err)),
+            None => write!(self.0, " {key}={value}"),
+        };
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    #[inline]
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.max_level
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            log::Level::Error => Level::Error,
+            log::Level::Warn => Level::Warn,
+            log::Level::Info => Level::Info,
+            log::Level::Debug | log::Level::Trace => Level::Debug,
+        };
+        let mut msg = record.args().to_string();
+        let _ = record.key_values().visit(&mut Fields(&mut msg));
+        super::log(level, &msg);
+    }
+
+    #[inline]
+    fn flush(&self) {
+        super::flush();
+    }
+}
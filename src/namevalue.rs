@@ -0,0 +1,369 @@
+//! A pure-Rust implementation of the name-value file format used by
+//! gpg-agent's extended private key format and other GnuPG files.
+//!
+//! Each entry starts with a name, which includes a trailing colon (e.g.
+//! `Created:`), followed by its value. Lines starting with whitespace
+//! continue the value of the preceding entry; each continuation line adds a
+//! newline and the line without its leading whitespace to the value. Blank
+//! lines and lines starting with `#` are comments, so a value cannot contain
+//! blank lines or end with a newline.
+//!
+//! Entries and comments keep their original bytes, so a file that is parsed
+//! and written back without modification is reproduced exactly.
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+use crate::{Error, ParseError, Result};
+
+#[derive(Debug, Clone)]
+enum Item {
+    Comment(String),
+    Entry(Entry),
+}
+
+impl Item {
+    fn raw(&self) -> &str {
+        match self {
+            Self::Comment(raw) => raw,
+            Self::Entry(e) => &e.raw,
+        }
+    }
+
+    fn raw_mut(&mut self) -> &mut String {
+        match self {
+            Self::Comment(raw) => raw,
+            Self::Entry(e) => &mut e.raw,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    name: String,
+    value: String,
+    raw: String,
+}
+
+impl Entry {
+    fn new(name: &str, value: &str) -> Self {
+        let mut raw = String::from(name);
+        let mut lines = value.split('\n');
+        if let Some(first) = lines.next().filter(|l| !l.is_empty()) {
+            raw.push(' ');
+            raw.push_str(first);
+        }
+        for line in lines {
+            raw.push_str("\n ");
+            raw.push_str(line);
+        }
+        raw.push('\n');
+        Self {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            raw,
+        }
+    }
+
+    fn push_continuation(&mut self, line: &str) {
+        self.value.push('\n');
+        self.value.push_str(
+            line.trim_start_matches([' ', '\t'])
+                .trim_end_matches(['\r', '\n']),
+        );
+        self.raw.push_str(line);
+    }
+}
+
+fn check_name(name: &str) -> Result<()> {
+    let Some(base) = name.strip_suffix(':') else {
+        return Err(Error::INV_NAME);
+    };
+    if !base.starts_with(|c: char| c.is_ascii_alphabetic())
+        || !base.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(Error::INV_NAME);
+    }
+    Ok(())
+}
+
+fn check_value(value: &str) -> Result<()> {
+    if value.split('\n').skip(1).any(|l| l.trim().is_empty()) {
+        return Err(Error::INV_VALUE);
+    }
+    Ok(())
+}
+
+/// An ordered list of name-value entries, including any comments.
+///
+/// Names are compared without regard to ASCII case.
+#[derive(Debug, Clone, Default)]
+pub struct NameValues {
+    items: Vec<Item>,
+    modified: bool,
+}
+
+impl NameValues {
+    /// Creates an empty list.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a name-value file from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::SYNTAX` for lines that are neither entries, comments
+    /// nor continuations of an entry, and `Error::INV_VALUE` for entries with
+    /// an invalid name or for data that is not UTF-8.
+    pub fn parse(mut reader: impl Read) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .map_err(|e| ParseError::new(e.into(), 0))?;
+        Self::from_bytes(&data)
+    }
+
+    /// Parses a name-value file from `data`.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ParseError> {
+        let mut items: Vec<Item> = Vec::new();
+        for (idx, line) in data.split_inclusive(|&b| b == b'\n').enumerate() {
+            let line_no = u32::try_from(idx + 1).unwrap_or(u32::MAX);
+            let fail = |error| ParseError::new(error, line_no);
+            let line = std::str::from_utf8(line).map_err(|_| fail(Error::INV_VALUE))?;
+            let content = line.trim_end_matches(['\r', '\n']);
+            if content.trim().is_empty() || content.starts_with('#') {
+                items.push(Item::Comment(line.to_owned()));
+            } else if content.starts_with([' ', '\t']) {
+                match items.last_mut() {
+                    Some(Item::Entry(entry)) => entry.push_continuation(line),
+                    _ => return Err(fail(Error::SYNTAX)),
+                }
+            } else {
+                let colon = content.find(':').ok_or_else(|| fail(Error::SYNTAX))?;
+                let (name, value) = content.split_at(colon + 1);
+                check_name(name).map_err(|_| fail(Error::INV_VALUE))?;
+                items.push(Item::Entry(Entry {
+                    name: name.to_owned(),
+                    value: value.trim_start_matches([' ', '\t']).to_owned(),
+                    raw: line.to_owned(),
+                }));
+            }
+        }
+        Ok(Self {
+            items,
+            modified: false,
+        })
+    }
+
+    /// Returns `true` if the list has been changed since it was created or
+    /// parsed.
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &Entry> + '_ {
+        self.items.iter().filter_map(|item| match item {
+            Item::Entry(e) => Some(e),
+            Item::Comment(_) => None,
+        })
+    }
+
+    /// Returns an iterator over all entries as name-value pairs.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.entries().map(|e| (e.name.as_str(), e.value.as_str()))
+    }
+
+    /// Returns the values of all entries called `name`.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.entries()
+            .filter(move |e| e.name.eq_ignore_ascii_case(name))
+            .map(|e| e.value.as_str())
+    }
+
+    /// Returns the value of the first entry called `name`.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+            .map(|e| e.value.as_str())
+    }
+
+    /// Returns the value of the first entry called `name` as a boolean.
+    ///
+    /// Values such as `yes`, `true` and non-zero numbers are considered true.
+    pub fn get_bool(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|v| {
+            let v = v.trim();
+            v.eq_ignore_ascii_case("yes")
+                || v.eq_ignore_ascii_case("true")
+                || v.parse::<i64>().is_ok_and(|n| n != 0)
+        })
+    }
+
+    /// Parses the value of the first entry called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_VALUE` if the value cannot be parsed.
+    pub fn get_parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.get(name)
+            .map(|v| v.parse().map_err(|_| Error::INV_VALUE))
+            .transpose()
+    }
+
+    /// Sets the value of the first entry called `name`, adding it if it
+    /// does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_NAME` if `name` is not a valid entry name and
+    /// `Error::INV_VALUE` if `value` contains a blank line or ends with a
+    /// newline.
+    pub fn set(&mut self, name: &str, value: impl fmt::Display) -> Result<()> {
+        check_name(name)?;
+        let value = value.to_string();
+        check_value(&value)?;
+        let existing = self.items.iter_mut().find_map(|item| match item {
+            Item::Entry(e) if e.name.eq_ignore_ascii_case(name) => Some(e),
+            _ => None,
+        });
+        match existing {
+            Some(entry) => {
+                let name = entry.name.clone();
+                *entry = Entry::new(&name, &value);
+                self.modified = true;
+            }
+            None => self.push(Entry::new(name, &value)),
+        }
+        Ok(())
+    }
+
+    /// Adds an entry called `name`, even if one already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_NAME` if `name` is not a valid entry name and
+    /// `Error::INV_VALUE` if `value` contains a blank line or ends with a
+    /// newline.
+    pub fn add(&mut self, name: &str, value: impl fmt::Display) -> Result<()> {
+        check_name(name)?;
+        let value = value.to_string();
+        check_value(&value)?;
+        self.push(Entry::new(name, &value));
+        Ok(())
+    }
+
+    fn push(&mut self, entry: Entry) {
+        if let Some(last) = self.items.last_mut() {
+            if !last.raw().ends_with('\n') {
+                last.raw_mut().push('\n');
+            }
+        }
+        self.items.push(Item::Entry(entry));
+        self.modified = true;
+    }
+
+    /// Deletes all entries called `name`.
+    pub fn delete(&mut self, name: &str) {
+        let len = self.items.len();
+        self.items
+            .retain(|item| !matches!(item, Item::Entry(e) if e.name.eq_ignore_ascii_case(name)));
+        self.modified |= self.items.len() != len;
+    }
+
+    /// Writes the list to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        for item in &self.items {
+            writer.write_all(item.raw().as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the serialized list.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.items
+            .iter()
+            .flat_map(|item| item.raw().as_bytes())
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for NameValues {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "# A private key\n\
+        Created: 20240101T000000\n\
+        Key: (private-key\n (rsa\n  (n #00C0FFEE#)\n  (e #010001#)))\r\n\
+        \n\
+        Use-for-ssh: yes";
+
+    #[test]
+    fn test_roundtrip() {
+        let nv: NameValues = KEY.parse().unwrap();
+        assert_eq!(nv.to_bytes(), KEY.as_bytes());
+        assert_eq!(nv.get("created:"), Some("20240101T000000"));
+        assert_eq!(
+            nv.get("Key:"),
+            Some("(private-key\n(rsa\n(n #00C0FFEE#)\n(e #010001#)))")
+        );
+        assert!(nv.get_bool("Use-for-ssh:"));
+        assert!(!nv.is_modified());
+    }
+
+    #[test]
+    fn test_modify() {
+        let mut nv: NameValues = KEY.parse().unwrap();
+        nv.set("Created:", 42).unwrap();
+        nv.add("Label:", "two\nlines").unwrap();
+        nv.delete("Use-for-ssh:");
+        assert!(nv.is_modified());
+        assert_eq!(nv.get_parsed::<u32>("Created:").unwrap(), Some(42));
+        assert!(nv.to_bytes().ends_with(b"\n\nLabel: two\n lines\n"));
+
+        let mut nv = NameValues::from_bytes(&nv.to_bytes()).unwrap();
+        assert_eq!(nv.get("Label:"), Some("two\nlines"));
+        assert_eq!(nv.set("No colon", 1).unwrap_err(), Error::INV_NAME);
+    }
+
+    #[test]
+    fn test_multiline_values() {
+        let mut nv = NameValues::new();
+        nv.add("A:", "\nfirst empty").unwrap();
+        nv.add("B:", "x\ny\nz").unwrap();
+        assert_eq!(nv.add("C:", "a\n\nb").unwrap_err(), Error::INV_VALUE);
+        assert_eq!(nv.add("C:", "a\n \nb").unwrap_err(), Error::INV_VALUE);
+        assert_eq!(nv.set("A:", "trailing\n").unwrap_err(), Error::INV_VALUE);
+
+        let parsed = NameValues::from_bytes(&nv.to_bytes()).unwrap();
+        assert_eq!(parsed.to_bytes(), nv.to_bytes());
+        assert_eq!(parsed.get("A:"), Some("\nfirst empty"));
+        assert_eq!(parsed.get("B:"), Some("x\ny\nz"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = NameValues::from_bytes(b" leading continuation\n").unwrap_err();
+        assert_eq!((err.error(), err.line()), (Error::SYNTAX, 1));
+        let err = NameValues::from_bytes(b"A: 1\nno colon here\n").unwrap_err();
+        assert_eq!((err.error(), err.line()), (Error::SYNTAX, 2));
+        let err = NameValues::from_bytes(b"1bad: x\n").unwrap_err();
+        assert_eq!(err.error(), Error::INV_VALUE);
+    }
+}
@@ -0,0 +1,271 @@
+//! Safe wrappers for libgpg-error's name-value containers.
+//!
+//! Name-value containers are used by gpg-agent's extended private key format
+//! (`private-keys-v1.d/*.key`) and several other GnuPG files. Names include
+//! their trailing colon, e.g. `Created:`. Comments and continuation lines
+//! are preserved when a parsed container is written back.
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    io::{self, Write},
+    os::raw::{c_char, c_uint},
+    ptr::{self, NonNull},
+    str::FromStr,
+};
+
+use crate::{estream::Stream, Error, ParseError, Result};
+
+#[inline]
+fn check(code: ffi::gpg_err_code_t) -> Result<()> {
+    match code {
+        0 => Ok(()),
+        code => Err(Error::new(code)),
+    }
+}
+
+#[inline]
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    s.as_ref().and_then(|s| CStr::from_ptr(s).to_str().ok())
+}
+
+/// A container of name-value pairs.
+pub struct NameValueContainer {
+    raw: NonNull<ffi::_gpgrt_name_value_container>,
+}
+
+unsafe impl Send for NameValueContainer {}
+
+impl Drop for NameValueContainer {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_nvc_release(self.as_raw());
+        }
+    }
+}
+
+impl NameValueContainer {
+    /// Creates an empty container.
+    #[inline]
+    pub fn new() -> Result<Self> {
+        Self::with_flags(0)
+    }
+
+    /// Creates an empty container for a private key, whose memory is wiped
+    /// when released.
+    #[inline]
+    pub fn new_private_key() -> Result<Self> {
+        Self::with_flags(ffi::GPGRT_NVC_PRIVKEY | ffi::GPGRT_NVC_WIPE)
+    }
+
+    fn with_flags(flags: c_uint) -> Result<Self> {
+        let raw = unsafe { ffi::gpgrt_nvc_new(flags) };
+        Ok(Self {
+            raw: NonNull::new(raw).ok_or_else(Error::last_os_error)?,
+        })
+    }
+
+    /// Parses a container from `stream`.
+    ///
+    /// If `private_key` is `true`, the container is parsed as a private key
+    /// file and its memory is wiped when released.
+    pub fn parse(stream: &mut Stream, private_key: bool) -> Result<Self, ParseError> {
+        let flags = if private_key {
+            ffi::GPGRT_NVC_PRIVKEY | ffi::GPGRT_NVC_WIPE
+        } else {
+            0
+        };
+        let mut raw = ptr::null_mut();
+        let mut line = 0;
+        unsafe {
+            check(ffi::gpgrt_nvc_parse(
+                &mut raw,
+                &mut line,
+                stream.as_raw(),
+                flags,
+            ))
+            .map_err(|e| ParseError::new(e, line.try_into().unwrap_or(0)))?;
+        }
+        Ok(Self {
+            raw: NonNull::new(raw).ok_or_else(|| ParseError::new(Error::GENERAL, 0))?,
+        })
+    }
+
+    /// Parses a container from `data`.
+    pub fn from_bytes(data: &[u8], private_key: bool) -> Result<Self, ParseError> {
+        let mut stream = Stream::from_bytes(data).map_err(|e| ParseError::new(e, 0))?;
+        Self::parse(&mut stream, private_key)
+    }
+
+    /// Returns the raw container handle.
+    #[inline]
+    pub fn as_raw(&self) -> ffi::gpgrt_nvc_t {
+        self.raw.as_ptr()
+    }
+
+    /// Returns `true` if the container has been changed since it was created
+    /// or parsed.
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        unsafe { ffi::gpgrt_nvc_get_flag(self.as_raw(), ffi::GPGRT_NVC_MODIFIED, 0) != 0 }
+    }
+
+    /// Returns the value of the first entry called `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let name = CString::new(name).ok()?;
+        unsafe { to_str(ffi::gpgrt_nvc_get_string(self.as_raw(), name.as_ptr())) }
+    }
+
+    /// Returns the value of the first entry called `name` as a boolean.
+    ///
+    /// Values such as `yes`, `true` and non-zero numbers are considered true.
+    pub fn get_bool(&self, name: &str) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe { ffi::gpgrt_nvc_get_bool(self.as_raw(), name.as_ptr()) != 0 }
+    }
+
+    /// Parses the value of the first entry called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_VALUE` if the value cannot be parsed.
+    pub fn get_parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.get(name)
+            .map(|v| v.parse().map_err(|_| Error::INV_VALUE))
+            .transpose()
+    }
+
+    /// Returns the values of all entries called `name`.
+    pub fn get_all<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a str> + 'a {
+        let name = CString::new(name).ok();
+        let first = name.as_ref().map_or(ptr::null_mut(), |n| unsafe {
+            ffi::gpgrt_nvc_lookup(self.as_raw(), n.as_ptr())
+        });
+        Entries {
+            next: first,
+            name,
+            _cont: self,
+        }
+        .map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over all entries as name-value pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        Entries {
+            next: unsafe { ffi::gpgrt_nvc_lookup(self.as_raw(), ptr::null()) },
+            name: None,
+            _cont: self,
+        }
+    }
+
+    /// Sets the value of the first entry called `name`, adding it if it
+    /// does not exist.
+    pub fn set(&mut self, name: &str, value: impl fmt::Display) -> Result<()> {
+        let name = CString::new(name)?;
+        let value = CString::new(value.to_string())?;
+        unsafe {
+            check(ffi::gpgrt_nvc_set(
+                self.as_raw(),
+                name.as_ptr(),
+                value.as_ptr(),
+            ))
+        }
+    }
+
+    /// Adds an entry called `name`, even if one already exists.
+    pub fn add(&mut self, name: &str, value: impl fmt::Display) -> Result<()> {
+        let name = CString::new(name)?;
+        let value = CString::new(value.to_string())?;
+        unsafe {
+            check(ffi::gpgrt_nvc_add(
+                self.as_raw(),
+                name.as_ptr(),
+                value.as_ptr(),
+            ))
+        }
+    }
+
+    /// Deletes all entries called `name`.
+    pub fn delete(&mut self, name: &str) {
+        // No entry can have a name containing a nul byte.
+        let Ok(name) = CString::new(name) else {
+            return;
+        };
+        unsafe {
+            ffi::gpgrt_nvc_delete(self.as_raw(), ptr::null_mut(), name.as_ptr());
+        }
+    }
+
+    /// Writes the container to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes()?)
+    }
+
+    /// Returns the serialized container.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let stream = Stream::memory(0)?;
+        unsafe {
+            check(ffi::gpgrt_nvc_write(self.as_raw(), stream.as_raw()))?;
+        }
+        stream.into_bytes()
+    }
+}
+
+impl fmt::Debug for NameValueContainer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NameValueContainer")
+            .field("raw", &self.raw)
+            .finish_non_exhaustive()
+    }
+}
+
+struct Entries<'a> {
+    next: ffi::gpgrt_nve_t,
+    name: Option<CString>,
+    _cont: &'a NameValueContainer,
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.next.is_null() {
+            let entry = self.next;
+            unsafe {
+                self.next = ffi::gpgrt_nve_next(
+                    entry,
+                    self.name.as_ref().map_or(ptr::null(), |n| n.as_ptr()),
+                );
+                if let (Some(name), Some(value)) = (
+                    to_str(ffi::gpgrt_nve_name(entry)),
+                    to_str(ffi::gpgrt_nve_value(entry)),
+                ) {
+                    return Some((name, value));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let data = b"# comment\nCreated: 20240101T000000\nKey: (private-key\n (rsa\n  (n #00#)))\n";
+        let mut nvc = NameValueContainer::from_bytes(data, true).unwrap();
+        assert_eq!(nvc.get("Created:"), Some("20240101T000000"));
+        assert!(!nvc.is_modified());
+        assert_eq!(nvc.to_bytes().unwrap(), data);
+
+        nvc.set("Use-for-ssh:", "yes").unwrap();
+        assert!(nvc.get_bool("Use-for-ssh:"));
+        nvc.delete("Created:");
+        assert_eq!(nvc.get("Created:"), None);
+        assert!(nvc.is_modified());
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    error, fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+/// An error encountered while parsing an option file, a name-value file or a
+/// similar line-based format.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    error: Error,
+    file: Option<PathBuf>,
+    line: u32,
+}
+
+impl ParseError {
+    pub(crate) fn new(error: Error, line: u32) -> Self {
+        Self {
+            error,
+            file: None,
+            line,
+        }
+    }
+
+    pub(crate) fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Returns the underlying error, usually `Error::SYNTAX` or
+    /// `Error::INV_VALUE`.
+    #[inline]
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Returns the file the error was found in, if known.
+    #[inline]
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Returns the line the error was found on, or zero if the error is not
+    /// associated with a line.
+    #[inline]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => f.write_str("<input>:")?,
+        }
+        if self.line != 0 {
+            write!(f, "{}:", self.line)?;
+        }
+        write!(f, " {}", self.error)
+    }
+}
+
+impl error::Error for ParseError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParseError> for Error {
+    #[inline]
+    fn from(err: ParseError) -> Self {
+        err.error
+    }
+}
@@ -0,0 +1,559 @@
+//! Safe wrappers for libgpg-error's process spawner.
+//!
+//! This is the spawner GnuPG itself uses, e.g. when gpgconf launches
+//! gpg-agent. Unlike [`std::process`], it can start detached daemons and
+//! hands out the standard streams of the child as [`Stream`]s.
+use std::{
+    collections::BTreeMap,
+    ffi::{CString, OsStr, OsString},
+    fmt,
+    os::raw::{c_char, c_int, c_uint},
+    ptr::{self, NonNull},
+};
+
+use crate::{estream::Stream, Error, ErrorCode, ErrorSource, Result};
+
+#[cfg(unix)]
+fn to_cstring(s: &OsStr) -> Result<CString> {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(CString::new(s.as_bytes())?)
+}
+
+#[cfg(not(unix))]
+fn to_cstring(s: &OsStr) -> Result<CString> {
+    Ok(CString::new(s.to_str().ok_or(Error::EINVAL)?)?)
+}
+
+/// How a standard stream of the child process is set up.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum Stdio {
+    /// Connect the stream to the null device.
+    #[default]
+    Null,
+    /// Share the stream with the parent process.
+    Inherit,
+    /// Connect the stream to a pipe, available from [`Child`].
+    Piped,
+}
+
+impl Stdio {
+    #[inline]
+    fn flags(self, keep: c_uint, pipe: c_uint) -> c_uint {
+        match self {
+            Self::Null => 0,
+            Self::Inherit => keep,
+            Self::Piped => pipe,
+        }
+    }
+}
+
+/// A builder for spawning processes.
+#[derive(Debug, Clone)]
+pub struct Command {
+    program: OsString,
+    args: Vec<OsString>,
+    stdin: Stdio,
+    stdout: Stdio,
+    stderr: Stdio,
+    env_clear: bool,
+    env: BTreeMap<OsString, Option<OsString>>,
+    #[cfg(unix)]
+    inherit_fds: Vec<c_int>,
+    source: ErrorSource,
+}
+
+impl Command {
+    /// Creates a builder for running `program`.
+    ///
+    /// The standard streams of the child are connected to the null device by
+    /// default.
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            program: program.as_ref().to_owned(),
+            args: Vec::new(),
+            stdin: Stdio::Null,
+            stdout: Stdio::Null,
+            stderr: Stdio::Null,
+            env_clear: false,
+            env: BTreeMap::new(),
+            #[cfg(unix)]
+            inherit_fds: Vec::new(),
+            source: ErrorSource::UNKNOWN,
+        }
+    }
+
+    /// Adds an argument.
+    #[inline]
+    pub fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds multiple arguments.
+    #[inline]
+    pub fn args<I>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|a| a.as_ref().to_owned()));
+        self
+    }
+
+    /// Configures the standard input of the child.
+    #[inline]
+    pub fn stdin(&mut self, cfg: Stdio) -> &mut Self {
+        self.stdin = cfg;
+        self
+    }
+
+    /// Configures the standard output of the child.
+    #[inline]
+    pub fn stdout(&mut self, cfg: Stdio) -> &mut Self {
+        self.stdout = cfg;
+        self
+    }
+
+    /// Configures the standard error of the child.
+    #[inline]
+    pub fn stderr(&mut self, cfg: Stdio) -> &mut Self {
+        self.stderr = cfg;
+        self
+    }
+
+    /// Sets an environment variable for the child.
+    #[inline]
+    pub fn env(&mut self, key: impl AsRef<OsStr>, val: impl AsRef<OsStr>) -> &mut Self {
+        self.env
+            .insert(key.as_ref().to_owned(), Some(val.as_ref().to_owned()));
+        self
+    }
+
+    /// Removes an environment variable for the child.
+    #[inline]
+    pub fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        self.env.insert(key.as_ref().to_owned(), None);
+        self
+    }
+
+    /// Clears the environment of the child, except for variables set
+    /// afterwards with [`env`](Self::env).
+    #[inline]
+    pub fn env_clear(&mut self) -> &mut Self {
+        self.env_clear = true;
+        self.env.clear();
+        self
+    }
+
+    /// Keeps the file descriptor `fd` open in the child.
+    #[cfg(unix)]
+    #[inline]
+    pub fn inherit_fd(&mut self, fd: i32) -> &mut Self {
+        self.inherit_fds.push(fd);
+        self
+    }
+
+    /// Sets the source attached to errors returned for this command and its
+    /// child process.
+    #[inline]
+    pub fn error_source(&mut self, source: ErrorSource) -> &mut Self {
+        self.source = source;
+        self
+    }
+
+    fn flags(&self) -> c_uint {
+        self.stdin
+            .flags(ffi::GPGRT_PROCESS_STDIN_KEEP, ffi::GPGRT_PROCESS_STDIN_PIPE)
+            | self.stdout.flags(
+                ffi::GPGRT_PROCESS_STDOUT_KEEP,
+                ffi::GPGRT_PROCESS_STDOUT_PIPE,
+            )
+            | self.stderr.flags(
+                ffi::GPGRT_PROCESS_STDERR_KEEP,
+                ffi::GPGRT_PROCESS_STDERR_PIPE,
+            )
+    }
+
+    fn environ(&self) -> Option<Vec<(OsString, OsString)>> {
+        if !self.env_clear && self.env.is_empty() {
+            return None;
+        }
+        let mut vars: BTreeMap<_, _> = if self.env_clear {
+            BTreeMap::new()
+        } else {
+            std::env::vars_os().map(|(k, v)| (k, Some(v))).collect()
+        };
+        vars.extend(self.env.clone());
+        Some(
+            vars.into_iter()
+                .filter_map(|(k, v)| Some((k, v?)))
+                .collect(),
+        )
+    }
+
+    #[cfg(unix)]
+    unsafe fn set_actions(&self, act: &mut Actions) -> Result<()> {
+        if let Some(vars) = self.environ() {
+            let mut environ = Vec::with_capacity(vars.len() + 1);
+            for (k, v) in vars {
+                let mut var = k;
+                var.push("=");
+                var.push(v);
+                let var = to_cstring(&var)?;
+                environ.push(var.as_ptr() as *mut c_char);
+                act.strings.push(var);
+            }
+            environ.push(ptr::null_mut());
+            act.environ = environ;
+            ffi::gpgrt_spawn_actions_set_environ(act.raw, act.environ.as_ptr() as *mut _);
+        }
+        if !self.inherit_fds.is_empty() {
+            act.fds = self.inherit_fds.iter().copied().chain([-1]).collect();
+            ffi::gpgrt_spawn_actions_set_inherit_fds(act.raw, act.fds.as_ptr());
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    unsafe fn set_actions(&self, act: &mut Actions) -> Result<()> {
+        if let Some(vars) = self.environ() {
+            let mut block = Vec::new();
+            for (k, v) in vars {
+                let var = format!(
+                    "{}={}",
+                    k.to_str().ok_or(Error::EINVAL)?,
+                    v.to_str().ok_or(Error::EINVAL)?
+                );
+                block.extend_from_slice(to_cstring(var.as_ref())?.as_bytes_with_nul());
+            }
+            block.push(0);
+            act.envvars = block;
+            ffi::gpgrt_spawn_actions_set_envvars(act.raw, act.envvars.as_mut_ptr().cast());
+        }
+        Ok(())
+    }
+
+    unsafe fn spawn_raw(&self, flags: c_uint, r_process: *mut ffi::gpgrt_process_t) -> Result<()> {
+        let check = check_fn(self.source);
+        let program = to_cstring(&self.program)?;
+        let args = self
+            .args
+            .iter()
+            .map(|a| to_cstring(a))
+            .collect::<Result<Vec<_>>>()?;
+        let mut argv: Vec<*const c_char> = args.iter().map(|a| a.as_ptr()).collect();
+        argv.push(ptr::null());
+
+        let mut act = Actions::new(check)?;
+        self.set_actions(&mut act)?;
+        check(ffi::gpgrt_process_spawn(
+            program.as_ptr(),
+            argv.as_mut_ptr(),
+            flags,
+            act.raw,
+            r_process,
+        ))
+    }
+
+    /// Spawns the command as a detached daemon, which keeps running after
+    /// the calling process exits and cannot be waited for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_FLAG` if any standard stream is set to
+    /// [`Stdio::Inherit`] or [`Stdio::Piped`], as the streams of a detached
+    /// process are always connected to the null device.
+    pub fn spawn_detached(&self) -> Result<()> {
+        if [self.stdin, self.stdout, self.stderr] != [Stdio::Null; 3] {
+            return Err(Error::from_source(self.source, ErrorCode::INV_FLAG));
+        }
+        unsafe { self.spawn_raw(ffi::GPGRT_PROCESS_DETACHED, ptr::null_mut()) }
+    }
+
+    /// Spawns the command as a child process.
+    pub fn spawn(&self) -> Result<Child> {
+        let check = check_fn(self.source);
+        let mut raw = ptr::null_mut();
+        unsafe {
+            self.spawn_raw(self.flags(), &mut raw)?;
+        }
+        let mut child = Child {
+            raw: NonNull::new(raw).ok_or(Error::GENERAL)?,
+            source: self.source,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        };
+        if [self.stdin, self.stdout, self.stderr].contains(&Stdio::Piped) {
+            let want = |cfg: Stdio, slot: &mut ffi::gpgrt_stream_t| -> *mut ffi::gpgrt_stream_t {
+                if cfg == Stdio::Piped {
+                    slot
+                } else {
+                    ptr::null_mut()
+                }
+            };
+            let (mut fp_in, mut fp_out, mut fp_err) =
+                (ptr::null_mut(), ptr::null_mut(), ptr::null_mut());
+            unsafe {
+                check(ffi::gpgrt_process_get_streams(
+                    raw,
+                    0,
+                    want(self.stdin, &mut fp_in),
+                    want(self.stdout, &mut fp_out),
+                    want(self.stderr, &mut fp_err),
+                ))?;
+                let wrap = |fp: ffi::gpgrt_stream_t| (!fp.is_null()).then(|| Stream::from_raw(fp));
+                child.stdin = wrap(fp_in);
+                child.stdout = wrap(fp_out);
+                child.stderr = wrap(fp_err);
+            }
+        }
+        Ok(child)
+    }
+}
+
+fn check_fn(source: ErrorSource) -> impl Fn(ffi::gpg_err_code_t) -> Result<()> + Copy {
+    move |code| match code {
+        0 => Ok(()),
+        code => Err(Error::from_source(source, ErrorCode::from_raw(code))),
+    }
+}
+
+/// Spawn actions and the data they point to, released when dropped.
+struct Actions {
+    raw: ffi::gpgrt_spawn_actions_t,
+    #[cfg(unix)]
+    strings: Vec<CString>,
+    #[cfg(unix)]
+    environ: Vec<*mut c_char>,
+    #[cfg(windows)]
+    envvars: Vec<u8>,
+    #[cfg(unix)]
+    fds: Vec<c_int>,
+}
+
+impl Actions {
+    unsafe fn new(check: impl Fn(ffi::gpg_err_code_t) -> Result<()>) -> Result<Self> {
+        let mut raw = ptr::null_mut();
+        check(ffi::gpgrt_spawn_actions_new(&mut raw))?;
+        Ok(Self {
+            raw,
+            #[cfg(unix)]
+            strings: Vec::new(),
+            #[cfg(unix)]
+            environ: Vec::new(),
+            #[cfg(windows)]
+            envvars: Vec::new(),
+            #[cfg(unix)]
+            fds: Vec::new(),
+        })
+    }
+}
+
+impl Drop for Actions {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_spawn_actions_release(self.raw);
+        }
+    }
+}
+
+/// The exit status of a child process.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ExitStatus(c_int);
+
+impl ExitStatus {
+    /// Returns `true` if the process exited successfully.
+    #[inline]
+    pub fn success(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the exit code of the process, or `None` if it was terminated
+    /// by a signal.
+    #[inline]
+    pub fn code(&self) -> Option<i32> {
+        (self.0 >= 0).then_some(self.0)
+    }
+}
+
+impl fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code() {
+            Some(code) => write!(f, "exit code: {code}"),
+            None => f.write_str("terminated abnormally"),
+        }
+    }
+}
+
+/// A running or exited child process.
+///
+/// Dropping a `Child` that has not been waited for terminates the process
+/// and waits for it to exit, as `gpgrt_process_release` does.
+pub struct Child {
+    raw: NonNull<ffi::gpgrt_process>,
+    source: ErrorSource,
+    /// The standard input of the child, if it was set to [`Stdio::Piped`].
+    pub stdin: Option<Stream>,
+    /// The standard output of the child, if it was set to [`Stdio::Piped`].
+    pub stdout: Option<Stream>,
+    /// The standard error of the child, if it was set to [`Stdio::Piped`].
+    pub stderr: Option<Stream>,
+}
+
+unsafe impl Send for Child {}
+
+impl Child {
+    /// Returns the raw process handle.
+    #[inline]
+    pub fn as_raw(&self) -> ffi::gpgrt_process_t {
+        self.raw.as_ptr()
+    }
+
+    #[inline]
+    fn check(&self, code: ffi::gpg_err_code_t) -> Result<()> {
+        check_fn(self.source)(code)
+    }
+
+    /// Returns the process id of the child.
+    pub fn id(&self) -> Result<i32> {
+        let mut id: c_int = 0;
+        unsafe {
+            self.check(ffi::gpgrt_process_ctl(
+                self.as_raw(),
+                ffi::GPGRT_PROCESS_GET_PROC_ID,
+                &mut id as *mut c_int,
+            ))?;
+        }
+        Ok(id)
+    }
+
+    /// Asks the child to terminate.
+    #[inline]
+    pub fn terminate(&mut self) -> Result<()> {
+        unsafe { self.check(ffi::gpgrt_process_terminate(self.as_raw())) }
+    }
+
+    fn exit_status(&self) -> Result<ExitStatus> {
+        let mut status: c_int = -1;
+        unsafe {
+            self.check(ffi::gpgrt_process_ctl(
+                self.as_raw(),
+                ffi::GPGRT_PROCESS_GET_EXIT_ID,
+                &mut status as *mut c_int,
+            ))?;
+        }
+        Ok(ExitStatus(status))
+    }
+
+    /// Closes the standard input of the child and waits for it to exit.
+    pub fn wait(&mut self) -> Result<ExitStatus> {
+        drop(self.stdin.take());
+        unsafe {
+            self.check(ffi::gpgrt_process_wait(self.as_raw(), 1))?;
+        }
+        self.exit_status()
+    }
+
+    /// Returns the exit status of the child if it has exited.
+    pub fn try_wait(&mut self) -> Result<Option<ExitStatus>> {
+        match unsafe { ffi::gpgrt_process_wait(self.as_raw(), 0) } {
+            ffi::GPG_ERR_TIMEOUT => Ok(None),
+            code => {
+                self.check(code)?;
+                self.exit_status().map(Some)
+            }
+        }
+    }
+}
+
+impl Drop for Child {
+    fn drop(&mut self) {
+        drop(self.stdin.take());
+        drop(self.stdout.take());
+        drop(self.stderr.take());
+        unsafe {
+            ffi::gpgrt_process_release(self.as_raw());
+        }
+    }
+}
+
+impl fmt::Debug for Child {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Child")
+            .field("raw", &self.raw)
+            .field("stdin", &self.stdin)
+            .field("stdout", &self.stdout)
+            .field("stderr", &self.stderr)
+            .finish()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{
+        io::Read,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_spawn() {
+        let mut child = Command::new("/bin/sh")
+            .args(["-c", "echo $GREETING; exit 3"])
+            .env("GREETING", "hello")
+            .stdout(Stdio::Piped)
+            .spawn()
+            .unwrap();
+        let mut out = String::new();
+        child
+            .stdout
+            .take()
+            .unwrap()
+            .read_to_string(&mut out)
+            .unwrap();
+        assert_eq!(out, "hello\n");
+        assert_eq!(child.wait().unwrap().code(), Some(3));
+
+        // The program is executed after forking, so a missing one is only
+        // reported through the exit status.
+        let mut child = Command::new("/nonexistent").spawn().unwrap();
+        assert_eq!(child.wait().unwrap().code(), Some(127));
+    }
+
+    #[test]
+    fn test_drop_terminates() {
+        let start = Instant::now();
+        let child = Command::new("/bin/sh")
+            .args(["-c", "sleep 60"])
+            .spawn()
+            .unwrap();
+        drop(child);
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_spawn_detached() {
+        let path = std::env::temp_dir().join(format!("gpg-error-detached-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Command::new("/bin/sh")
+            .args(["-c", "echo ok > \"$0\""])
+            .arg(path.to_str().unwrap())
+            .spawn_detached()
+            .unwrap();
+        let start = Instant::now();
+        while std::fs::read_to_string(&path).ok().as_deref() != Some("ok\n") {
+            assert!(start.elapsed() < Duration::from_secs(30));
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        let err = Command::new("/bin/true")
+            .stdout(Stdio::Piped)
+            .spawn_detached()
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::INV_FLAG);
+    }
+}
@@ -0,0 +1,7 @@
+error_ranges! {
+    Assuan = 257..=299,
+    Dns = 711..=718,
+    Ldap = 721..=950,
+    User = 1024..=1039,
+    Sql = 1500..=1755,
+}
@@ -0,0 +1,231 @@
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    error,
+    ffi::NulError,
+    fmt, io,
+    num::TryFromIntError,
+};
+
+use crate::{Error, ParseError};
+
+type Cause = Box<dyn error::Error + Send + Sync + 'static>;
+
+/// An [`Error`] with an optional message, the error that caused it and a
+/// backtrace.
+///
+/// A report converts back to the `Error` it carries, e.g. to return it
+/// across an FFI boundary. The backtrace is only captured if enabled through
+/// the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables.
+pub struct Report {
+    error: Error,
+    message: Option<String>,
+    cause: Option<Cause>,
+    backtrace: Backtrace,
+}
+
+impl Report {
+    /// Creates a new report for `error`.
+    #[inline]
+    pub fn new(error: Error) -> Self {
+        Self {
+            error,
+            message: None,
+            cause: None,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates a new report for `error` caused by `cause`.
+    #[inline]
+    pub fn with_cause(error: Error, cause: impl Into<Cause>) -> Self {
+        Self {
+            cause: Some(cause.into()),
+            ..Self::new(error)
+        }
+    }
+
+    /// Adds a message describing what was being done when the error
+    /// occurred.
+    ///
+    /// If the report already has a message, it becomes the cause of a new
+    /// report for the same error.
+    pub fn context(self, message: impl fmt::Display) -> Self {
+        let message = Some(message.to_string());
+        if self.message.is_none() {
+            return Self { message, ..self };
+        }
+        Self {
+            error: self.error,
+            message,
+            cause: Some(Box::new(self)),
+            backtrace: Backtrace::disabled(),
+        }
+    }
+
+    /// Returns the error carried by the report.
+    #[inline]
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Returns the message of the report, if any.
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the error that caused this one, if any.
+    #[inline]
+    pub fn cause(&self) -> Option<&(dyn error::Error + Send + Sync + 'static)> {
+        self.cause.as_deref()
+    }
+
+    /// Returns the backtrace captured where the error was first reported.
+    pub fn backtrace(&self) -> &Backtrace {
+        match self.cause().and_then(|c| c.downcast_ref::<Self>()) {
+            Some(inner) if self.backtrace.status() != BacktraceStatus::Captured => {
+                inner.backtrace()
+            }
+            _ => &self.backtrace,
+        }
+    }
+}
+
+impl error::Error for Report {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.cause {
+            Some(cause) => Some(&**cause),
+            None if self.message.is_some() => Some(&self.error),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Report")
+            .field("error", &self.error)
+            .field("message", &self.message)
+            .field("cause", &self.cause)
+            .field("backtrace", &self.backtrace)
+            .finish()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => f.write_str(message),
+            None => fmt::Display::fmt(&self.error, f),
+        }
+    }
+}
+
+impl From<Error> for Report {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Self::new(error)
+    }
+}
+
+impl From<Report> for Error {
+    #[inline]
+    fn from(report: Report) -> Self {
+        report.error
+    }
+}
+
+impl From<Report> for io::Error {
+    fn from(report: Report) -> Self {
+        Self::new(io::Error::from(report.error).kind(), report)
+    }
+}
+
+impl From<io::Error> for Report {
+    fn from(err: io::Error) -> Self {
+        let error = Error::from_io_error(&err);
+        if err.get_ref().is_some_and(|e| e.is::<Error>()) {
+            Self::new(error)
+        } else {
+            Self::with_cause(error, err)
+        }
+    }
+}
+
+impl From<ParseError> for Report {
+    #[inline]
+    fn from(err: ParseError) -> Self {
+        Self::with_cause(err.error(), err)
+    }
+}
+
+impl From<NulError> for Report {
+    #[inline]
+    fn from(err: NulError) -> Self {
+        Self::with_cause(Error::EINVAL, err)
+    }
+}
+
+impl From<TryFromIntError> for Report {
+    #[inline]
+    fn from(err: TryFromIntError) -> Self {
+        Self::with_cause(Error::EINVAL, err)
+    }
+}
+
+/// Adds context to the errors of a [`Result`](std::result::Result).
+pub trait Context<T> {
+    /// Converts the error to a [`Report`] with the given message.
+    fn context(self, message: impl fmt::Display) -> Result<T, Report>;
+
+    /// Converts the error to a [`Report`] with the message returned by `f`,
+    /// which is only called if there is an error.
+    fn with_context<M: fmt::Display>(self, f: impl FnOnce() -> M) -> Result<T, Report>;
+}
+
+impl<T, E: Into<Report>> Context<T> for Result<T, E> {
+    #[inline]
+    fn context(self, message: impl fmt::Display) -> Result<T, Report> {
+        self.map_err(|e| e.into().context(message))
+    }
+
+    #[inline]
+    fn with_context<M: fmt::Display>(self, f: impl FnOnce() -> M) -> Result<T, Report> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error as _, fs::File};
+
+    use super::*;
+
+    #[test]
+    fn test_context() {
+        let report = File::open("/nonexistent/gpg-error")
+            .context("opening config")
+            .context("loading settings")
+            .unwrap_err();
+        assert_eq!(report.to_string(), "loading settings");
+        assert_eq!(report.error(), Error::ENOENT);
+
+        let chain: Vec<_> = std::iter::successors(report.source(), |&e| e.source()).collect();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].to_string(), "opening config");
+        assert!(chain[1].is::<io::Error>());
+
+        let err = io::Error::from(Report::new(Error::EPIPE).context("writing"));
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(Error::from(err), Error::EPIPE);
+    }
+
+    #[test]
+    fn test_message_only() {
+        let report = Report::new(Error::INV_VALUE).context("bad key");
+        assert_eq!(report.message(), Some("bad key"));
+        let source = report.source().unwrap();
+        assert_eq!(source.downcast_ref::<Error>(), Some(&Error::INV_VALUE));
+        assert_eq!(Error::from(report), Error::INV_VALUE);
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::Result;
+
+/// A source of time used by [`retry_with_clock`].
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+
+    /// Blocks for the given duration.
+    fn sleep(&self, duration: Duration);
+}
+
+/// A [`Clock`] using the system time and [`thread::sleep`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    #[inline]
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// An exponential backoff policy for [`retry`].
+///
+/// By default, an operation is attempted up to 5 times, waiting 100ms
+/// before the first retry and doubling the delay after each one, up to a
+/// maximum of 5s.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    max_attempts: u32,
+    max_elapsed: Option<Duration>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2,
+            max_attempts: 5,
+            max_elapsed: None,
+        }
+    }
+}
+
+impl Backoff {
+    /// Creates the default policy.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the factor the delay is multiplied by after each retry.
+    pub fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Stops retrying if the next attempt would start more than `elapsed`
+    /// after the first one.
+    pub fn max_elapsed(mut self, elapsed: Duration) -> Self {
+        self.max_elapsed = Some(elapsed);
+        self
+    }
+
+    /// Returns the delay before the given retry, starting at 0.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(retry);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// Calls `f` until it succeeds, fails with an error that is not
+/// [transient](crate::Error::is_transient) or `backoff` gives up.
+///
+/// `f` is passed the number of the attempt, starting at 1. The last error is
+/// returned if all attempts fail.
+#[inline]
+pub fn retry<T>(backoff: &Backoff, f: impl FnMut(u32) -> Result<T>) -> Result<T> {
+    retry_with_clock(backoff, &SystemClock, f)
+}
+
+/// Like [`retry`], but uses `clock` to measure and wait for the delays.
+pub fn retry_with_clock<T>(
+    backoff: &Backoff,
+    clock: &impl Clock,
+    mut f: impl FnMut(u32) -> Result<T>,
+) -> Result<T> {
+    let start = clock.now();
+    let mut attempt = 1;
+    loop {
+        let err = match f(attempt) {
+            Ok(v) => return Ok(v),
+            Err(e) => e,
+        };
+        if !err.is_transient() || attempt >= backoff.max_attempts {
+            return Err(err);
+        }
+        let delay = backoff.delay(attempt - 1);
+        if let Some(max) = backoff.max_elapsed {
+            if clock.now().saturating_duration_since(start) + delay > max {
+                return Err(err);
+            }
+        }
+        clock.sleep(delay);
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::Error;
+
+    struct TestClock {
+        start: Instant,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl TestClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                sleeps: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Clock for TestClock {
+        fn now(&self) -> Instant {
+            self.start + self.sleeps.borrow().iter().sum::<Duration>()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    #[test]
+    fn test_retry() {
+        let backoff = Backoff::new()
+            .initial_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(3));
+        let clock = TestClock::new();
+        let result = retry_with_clock(&backoff, &clock, |n| {
+            if n < 4 {
+                Err(Error::TIMEOUT)
+            } else {
+                Ok(n)
+            }
+        });
+        assert_eq!(result, Ok(4));
+        assert_eq!(
+            clock.sleeps.into_inner(),
+            [1, 2, 3].map(Duration::from_secs)
+        );
+
+        let clock = TestClock::new();
+        let result = retry_with_clock(&backoff, &clock, |_| Err::<(), _>(Error::NO_AGENT));
+        assert_eq!(result, Err(Error::NO_AGENT));
+        assert_eq!(clock.sleeps.into_inner().len(), 4);
+    }
+
+    #[test]
+    fn test_permanent() {
+        let clock = TestClock::new();
+        let mut calls = 0;
+        let result = retry_with_clock(&Backoff::new(), &clock, |_| {
+            calls += 1;
+            Err::<(), _>(Error::BAD_SIGNATURE)
+        });
+        assert_eq!(result, Err(Error::BAD_SIGNATURE));
+        assert_eq!(calls, 1);
+
+        let backoff = Backoff::new()
+            .initial_delay(Duration::from_secs(1))
+            .max_elapsed(Duration::from_secs(4));
+        let clock = TestClock::new();
+        let _ = retry_with_clock(&backoff, &clock, |_| Err::<(), _>(Error::EAGAIN));
+        assert_eq!(clock.sleeps.into_inner(), [1, 2].map(Duration::from_secs));
+    }
+}
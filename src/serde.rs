@@ -0,0 +1,404 @@
+//! Serialization of errors with [serde](https://serde.rs).
+//!
+//! By default, [`Error`], [`ErrorCode`] and [`ErrorSource`] are serialized
+//! as their raw `u32` values. The [`structured`] and [`symbolic`] modules
+//! provide other representations of `Error` for use with
+//! `#[serde(with = "...")]`:
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Response {
+//!     #[serde(with = "gpg_error::serde::symbolic")]
+//!     error: gpg_error::Error, // "GPGME/NO_PUBKEY"
+//! }
+//! # let error = gpg_error::Error::NO_PUBKEY.with_source(gpg_error::Error::SOURCE_GPGME);
+//! # let json = serde_json::to_string(&Response { error }).unwrap();
+//! # assert_eq!(json, r#"{"error":"GPGME/NO_PUBKEY"}"#);
+//! # let response: Response = serde_json::from_str(&json).unwrap();
+//! # assert_eq!(response.error, error);
+//! ```
+//!
+//! With self-describing formats such as JSON, all representations accept
+//! each other's output. Codes and sources are checked to be in range.
+use alloc::string::String;
+use core::fmt;
+
+use ::serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+use crate::{Error, ErrorCode, ErrorSource};
+
+const CODE_PREFIX: &str = "GPG_ERR_";
+const SOURCE_PREFIX: &str = "GPG_ERR_SOURCE_";
+const FIELDS: &[&str] = &["code", "source", "name", "description"];
+
+/// Serializes errors as their raw `u32` value, e.g. `117440521`.
+///
+/// This is the same representation as the `Serialize` implementation of
+/// `Error`.
+pub mod compact {
+    use super::*;
+
+    /// Serializes `err` as a `u32`.
+    #[inline]
+    pub fn serialize<S: Serializer>(err: &Error, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(err.raw())
+    }
+
+    /// Deserializes an error from any of the supported representations.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Error, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ErrorVisitor)
+        } else {
+            deserializer.deserialize_u32(ErrorVisitor)
+        }
+    }
+}
+
+/// Serializes errors as a struct with the fields `code`, `source`, `name`
+/// and `description`, e.g.
+/// `{"code": 9, "source": 7, "name": "GPG_ERR_NO_PUBKEY", "description": "No public key"}`.
+///
+/// Only `code` and `source` are used when deserializing, with `name` as a
+/// fallback if there is no `code`. Both fields may also be symbolic names.
+pub mod structured {
+    use super::*;
+
+    /// Serializes `err` as a struct.
+    pub fn serialize<S: Serializer>(err: &Error, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Error", FIELDS.len())?;
+        s.serialize_field("code", &err.raw_code())?;
+        s.serialize_field("source", &err.error_source().raw())?;
+        s.serialize_field("name", &err.code_name())?;
+        s.serialize_field("description", &err.description())?;
+        s.end()
+    }
+
+    /// Deserializes an error from any of the supported representations.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Error, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ErrorVisitor)
+        } else {
+            deserializer.deserialize_struct("Error", FIELDS, ErrorVisitor)
+        }
+    }
+}
+
+/// Serializes errors as a `SOURCE/CODE` string of symbolic names without
+/// their prefixes, e.g. `"GPGME/NO_PUBKEY"`.
+///
+/// Sources and codes without a name are written as numbers. When
+/// deserializing, the source may be omitted (`"NO_PUBKEY"`), in which case
+/// `SOURCE_UNKNOWN` is used, and names are matched ignoring case.
+pub mod symbolic {
+    use super::*;
+
+    /// Serializes `err` as a string.
+    #[inline]
+    pub fn serialize<S: Serializer>(err: &Error, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Symbolic(*err))
+    }
+
+    /// Deserializes an error from any of the supported representations.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Error, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ErrorVisitor)
+        } else {
+            deserializer.deserialize_str(ErrorVisitor)
+        }
+    }
+}
+
+struct Symbolic(Error);
+
+impl fmt::Display for Symbolic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = self.0.error_source();
+        match source.name() {
+            Some(name) => f.write_str(&name[SOURCE_PREFIX.len()..])?,
+            None => write!(f, "{}", source.raw())?,
+        }
+        match self.0.code_name() {
+            Some(name) => write!(f, "/{}", &name[CODE_PREFIX.len()..]),
+            None => write!(f, "/{}", self.0.raw_code()),
+        }
+    }
+}
+
+fn parse_code(s: &str) -> Option<ErrorCode> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        let code = s.parse().ok().filter(|&c| c < ffi::GPG_ERR_CODE_DIM)?;
+        return Some(ErrorCode::from_raw(code));
+    }
+    ErrorCode::from_name(s)
+}
+
+fn parse_source(s: &str) -> Option<ErrorSource> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        return s.parse().ok().and_then(ErrorSource::new);
+    }
+    s.parse().ok()
+}
+
+fn parse_symbolic(s: &str) -> Option<Error> {
+    let (source, code) = match s.split_once('/') {
+        Some((source, code)) => (parse_source(source)?, code),
+        None => (Error::SOURCE_UNKNOWN, s),
+    };
+    Some(Error::from_source(source, parse_code(code)?))
+}
+
+struct ErrorVisitor;
+
+impl<'de> Visitor<'de> for ErrorVisitor {
+    type Value = Error;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a gpg error value, struct or `SOURCE/CODE` string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Error, E> {
+        const MASK: u64 = (ffi::GPG_ERR_CODE_MASK
+            | (ffi::GPG_ERR_SOURCE_MASK << ffi::GPG_ERR_SOURCE_SHIFT))
+            as u64;
+        if v & !MASK != 0 {
+            return Err(E::invalid_value(Unexpected::Unsigned(v), &self));
+        }
+        Ok(Error::new(v as ffi::gpg_error_t))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Error, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Error, E> {
+        parse_symbolic(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Error, A::Error> {
+        let code: ErrorCode = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let source: ErrorSource = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        // The name and description are read with their types for formats
+        // that are not self-describing.
+        seq.next_element::<Option<String>>()?;
+        seq.next_element::<String>()?;
+        Ok(Error::from_source(source, code))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Error, A::Error> {
+        let mut code = None;
+        let mut source = None;
+        let mut name = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match &*key {
+                "code" => code = Some(map.next_value::<ErrorCode>()?),
+                "source" => source = Some(map.next_value::<ErrorSource>()?),
+                "name" => name = map.next_value::<Option<String>>()?,
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        let code = match (code, name) {
+            (Some(code), _) => code,
+            (None, Some(name)) => ErrorCode::from_name(&name).ok_or_else(|| {
+                de::Error::invalid_value(Unexpected::Str(&name), &"an error code")
+            })?,
+            (None, None) => return Err(de::Error::missing_field("code")),
+        };
+        Ok(Error::from_source(
+            source.unwrap_or(Error::SOURCE_UNKNOWN),
+            code,
+        ))
+    }
+}
+
+impl Serialize for Error {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        compact::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        compact::deserialize(deserializer)
+    }
+}
+
+struct CodeVisitor;
+
+impl Visitor<'_> for CodeVisitor {
+    type Value = ErrorCode;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a gpg error code or its name")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<ErrorCode, E> {
+        match ffi::gpg_err_code_t::try_from(v) {
+            Ok(code) if code < ffi::GPG_ERR_CODE_DIM => Ok(ErrorCode::from_raw(code)),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<ErrorCode, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<ErrorCode, E> {
+        parse_code(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl Serialize for ErrorCode {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.raw())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CodeVisitor)
+        } else {
+            deserializer.deserialize_u32(CodeVisitor)
+        }
+    }
+}
+
+struct SourceVisitor;
+
+impl Visitor<'_> for SourceVisitor {
+    type Value = ErrorSource;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a gpg error source or its name")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<ErrorSource, E> {
+        ffi::gpg_err_source_t::try_from(v)
+            .ok()
+            .and_then(ErrorSource::new)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<ErrorSource, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<ErrorSource, E> {
+        parse_source(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl Serialize for ErrorSource {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.raw())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorSource {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SourceVisitor)
+        } else {
+            deserializer.deserialize_u32(SourceVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, json, to_value, value::Serializer as ValueSerializer};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let err = Error::from_source(Error::SOURCE_GPGME, ErrorCode::NO_PUBKEY);
+        assert_eq!(to_value(err).unwrap(), json!(err.raw()));
+        assert_eq!(
+            symbolic::serialize(&err, ValueSerializer).unwrap(),
+            json!("GPGME/NO_PUBKEY")
+        );
+        assert_eq!(
+            symbolic::serialize(&Error::new(12345), ValueSerializer).unwrap(),
+            json!("UNKNOWN/12345")
+        );
+        assert_eq!(
+            structured::serialize(&err, ValueSerializer).unwrap(),
+            json!({
+                "code": 9,
+                "source": 7,
+                "name": "GPG_ERR_NO_PUBKEY",
+                "description": err.description(),
+            })
+        );
+        assert_eq!(to_value(ErrorCode::EOF).unwrap(), json!(16383));
+        assert_eq!(to_value(Error::SOURCE_GPGME).unwrap(), json!(7));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let err = Error::from_source(Error::SOURCE_GPGME, ErrorCode::NO_PUBKEY);
+        for s in [
+            "117440521",
+            r#""GPGME/NO_PUBKEY""#,
+            r#""gpgme/GPG_ERR_NO_PUBKEY""#,
+            r#""7/9""#,
+            r#"{"code": 9, "source": 7}"#,
+            r#"{"code": "NO_PUBKEY", "source": "GPGME", "description": "x"}"#,
+            r#"{"source": 7, "name": "GPG_ERR_NO_PUBKEY"}"#,
+            r#"[9, 7, null, "x"]"#,
+        ] {
+            assert_eq!(from_str::<Error>(s).unwrap(), err, "{s}");
+        }
+        assert_eq!(from_str::<Error>(r#""EOF""#).unwrap(), Error::EOF);
+        assert_eq!(
+            from_str::<ErrorCode>(r#""EPIPE""#).unwrap(),
+            ErrorCode::EPIPE
+        );
+        assert_eq!(from_str::<ErrorSource>("7").unwrap(), Error::SOURCE_GPGME);
+
+        for s in [
+            "-1",
+            "4294967295",
+            "65536000",
+            r#""GPGME/NOT_A_CODE""#,
+            r#""128/1""#,
+            r#""GPGME/65536""#,
+            r#"{"code": 65536}"#,
+            r#"{"code": 1, "source": 128}"#,
+            r#"{"source": 7}"#,
+        ] {
+            assert!(from_str::<Error>(s).is_err(), "{s}");
+        }
+        assert!(from_str::<ErrorSource>("128").is_err());
+        assert!(from_str::<ErrorCode>("65536").is_err());
+    }
+}
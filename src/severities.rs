@@ -0,0 +1,55 @@
+error_severities! {
+    Recoverable => [
+        TIMEOUT,
+        TRY_LATER,
+        NETWORK,
+        KEYSERVER,
+        NO_AGENT,
+        NO_DIRMNGR,
+        NO_KEYBOXD,
+        NO_SERVICE,
+        ASS_CONNECT_FAILED,
+        DNS_TIMEOUT,
+        LDAP_CONNECT,
+        LDAP_TIMEOUT,
+        LDAP_SERVER_DOWN,
+        LDAP_BUSY,
+        LDAP_UNAVAILABLE,
+        SQL_BUSY,
+        SQL_LOCKED,
+        EAGAIN,
+        EWOULDBLOCK,
+        EINTR,
+        EBUSY,
+        ETIMEDOUT,
+        ECONNABORTED,
+        ECONNREFUSED,
+        ECONNRESET,
+        EHOSTUNREACH,
+        ENETDOWN,
+        ENETUNREACH,
+    ],
+    UserActionRequired => [
+        BAD_PASSPHRASE,
+        NO_PASSPHRASE,
+        BAD_PIN,
+        NO_PIN,
+        PIN_BLOCKED,
+        BAD_PUK,
+        NOT_CONFIRMED,
+        CARD_NOT_PRESENT,
+        CARD_REMOVED,
+        NO_PUBKEY,
+        NO_SECKEY,
+        UNUSABLE_PUBKEY,
+        // Expired keys and certificates can be renewed, unlike revoked ones.
+        KEY_EXPIRED,
+        CERT_EXPIRED,
+        NO_KEYSERVER,
+        NO_AUTH,
+        BAD_AUTH,
+        LDAP_INV_CREDENTIALS,
+        EACCES,
+        EPERM,
+    ],
+}
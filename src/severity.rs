@@ -0,0 +1,70 @@
+use crate::{Error, ErrorCode};
+
+/// How an error should be handled by the code receiving it.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Severity {
+    /// Retrying the operation will not help.
+    Fatal,
+    /// The operation may succeed if it is retried later, e.g. after a
+    /// timeout or once a service has started.
+    Recoverable,
+    /// The operation may succeed once the user has acted, e.g. by entering
+    /// the correct passphrase, inserting a card or importing a key.
+    UserActionRequired,
+}
+
+macro_rules! error_severities {
+    ($($severity:ident => [$($code:ident),* $(,)?]),* $(,)?) => {
+        impl Error {
+            /// Returns how the error should be handled.
+            ///
+            /// The error source is not taken into account. Codes not listed
+            /// as recoverable or requiring user action are fatal.
+            pub const fn severity(&self) -> Severity {
+                match self.code() {
+                    $($(ErrorCode::$code)|* => Severity::$severity,)*
+                    _ => Severity::Fatal,
+                }
+            }
+        }
+    };
+}
+
+include!("severities.rs");
+
+impl Error {
+    /// Returns `true` if the operation that failed with this error may
+    /// succeed if it is retried without any other changes.
+    #[inline]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self.severity(), Severity::Recoverable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity() {
+        assert_eq!(Error::DNS_TIMEOUT.severity(), Severity::Recoverable);
+        assert!(Error::LDAP_BUSY.is_transient());
+        assert!(Error::EAGAIN
+            .with_source(Error::SOURCE_GPGAGENT)
+            .is_transient());
+        assert_eq!(
+            Error::BAD_PASSPHRASE.severity(),
+            Severity::UserActionRequired
+        );
+        assert_eq!(Error::BAD_SIGNATURE.severity(), Severity::Fatal);
+        assert!(!Error::NO_PUBKEY.is_transient());
+    }
+
+    #[test]
+    fn test_expired_and_revoked() {
+        assert_eq!(Error::KEY_EXPIRED.severity(), Severity::UserActionRequired);
+        assert_eq!(Error::CERT_EXPIRED.severity(), Severity::UserActionRequired);
+        assert_eq!(Error::CERT_REVOKED.severity(), Severity::Fatal);
+    }
+}
@@ -0,0 +1,132 @@
+use core::{ffi::CStr, fmt, str::FromStr};
+
+use crate::Error;
+
+const PREFIX: &str = "GPG_ERR_SOURCE_";
+
+/// An error source, identifying the component that produced an error.
+///
+/// Values are guaranteed to be smaller than `GPG_ERR_SOURCE_DIM`.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ErrorSource(ffi::gpg_err_source_t);
+
+macro_rules! error_sources {
+    ($($name:ident = $value:path),* $(,)?) => {
+        impl ErrorSource {
+            $(pub const $name: Self = Self($value);)*
+
+            const KNOWN: &'static [(Self, &'static str)] = &[
+                $((Self::$name, concat!("GPG_ERR_SOURCE_", stringify!($name))),)*
+            ];
+        }
+    };
+}
+
+include!("sources.rs");
+
+impl ErrorSource {
+    /// Creates a new error source from a raw source value.
+    ///
+    /// Returns `None` if the value is out of range.
+    #[inline]
+    pub const fn new(source: ffi::gpg_err_source_t) -> Option<Self> {
+        if source < ffi::GPG_ERR_SOURCE_DIM {
+            Some(Self(source))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the raw value of the error source.
+    #[inline]
+    pub const fn raw(&self) -> ffi::gpg_err_source_t {
+        self.0
+    }
+
+    /// Returns the symbolic name of the error source (e.g. `GPG_ERR_SOURCE_GPGME`),
+    /// if it is known.
+    pub fn name(&self) -> Option<&'static str> {
+        Self::KNOWN
+            .iter()
+            .find(|(s, _)| s == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Returns a description of the error source.
+    #[inline]
+    pub fn description(&self) -> &'static str {
+        unsafe {
+            ffi::gpg_strsource(ffi::gpg_err_make(self.0, ffi::GPG_ERR_GENERAL))
+                .as_ref()
+                .and_then(|s| CStr::from_ptr(s).to_str().ok())
+                .unwrap_or("Unknown source")
+        }
+    }
+
+    /// Returns an iterator over all error sources that have a symbolic name.
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Self> + Clone {
+        Self::KNOWN.iter().map(|&(s, _)| s)
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => f.debug_tuple("ErrorSource").field(&self.0).finish(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl FromStr for ErrorSource {
+    type Err = Error;
+
+    /// Parses an error source from its symbolic name.
+    ///
+    /// Both the full name (`GPG_ERR_SOURCE_GPGME`) and the name without
+    /// the prefix (`gpgme`) are accepted, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_NAME` if the name is not recognized.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let short = match s.get(..PREFIX.len()) {
+            Some(p) if p.eq_ignore_ascii_case(PREFIX) => &s[PREFIX.len()..],
+            _ => s,
+        };
+        Self::KNOWN
+            .iter()
+            .find(|(_, name)| name[PREFIX.len()..].eq_ignore_ascii_case(short))
+            .map(|&(s, _)| s)
+            .ok_or(Error::INV_NAME)
+    }
+}
+
+impl TryFrom<ffi::gpg_err_source_t> for ErrorSource {
+    type Error = Error;
+
+    /// Converts a raw source value into an `ErrorSource`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::EINVAL` if the value is out of range.
+    #[inline]
+    fn try_from(source: ffi::gpg_err_source_t) -> Result<Self, Error> {
+        Self::new(source).ok_or(Error::EINVAL)
+    }
+}
+
+impl From<ErrorSource> for ffi::gpg_err_source_t {
+    #[inline]
+    fn from(source: ErrorSource) -> Self {
+        source.0
+    }
+}
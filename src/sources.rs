@@ -0,0 +1,26 @@
+error_sources! {
+    UNKNOWN = ffi::GPG_ERR_SOURCE_UNKNOWN,
+    GCRYPT = ffi::GPG_ERR_SOURCE_GCRYPT,
+    GPG = ffi::GPG_ERR_SOURCE_GPG,
+    GPGSM = ffi::GPG_ERR_SOURCE_GPGSM,
+    GPGAGENT = ffi::GPG_ERR_SOURCE_GPGAGENT,
+    PINENTRY = ffi::GPG_ERR_SOURCE_PINENTRY,
+    SCD = ffi::GPG_ERR_SOURCE_SCD,
+    GPGME = ffi::GPG_ERR_SOURCE_GPGME,
+    KEYBOX = ffi::GPG_ERR_SOURCE_KEYBOX,
+    KSBA = ffi::GPG_ERR_SOURCE_KSBA,
+    DIRMNGR = ffi::GPG_ERR_SOURCE_DIRMNGR,
+    GSTI = ffi::GPG_ERR_SOURCE_GSTI,
+    GPA = ffi::GPG_ERR_SOURCE_GPA,
+    KLEO = ffi::GPG_ERR_SOURCE_KLEO,
+    G13 = ffi::GPG_ERR_SOURCE_G13,
+    ASSUAN = ffi::GPG_ERR_SOURCE_ASSUAN,
+    TPM2D = ffi::GPG_ERR_SOURCE_TPM2D,
+    TLS = ffi::GPG_ERR_SOURCE_TLS,
+    TKD = ffi::GPG_ERR_SOURCE_TKD,
+    ANY = ffi::GPG_ERR_SOURCE_ANY,
+    USER_1 = ffi::GPG_ERR_SOURCE_USER_1,
+    USER_2 = ffi::GPG_ERR_SOURCE_USER_2,
+    USER_3 = ffi::GPG_ERR_SOURCE_USER_3,
+    USER_4 = ffi::GPG_ERR_SOURCE_USER_4,
+}
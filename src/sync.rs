@@ -0,0 +1,208 @@
+//! Synchronization primitives backed by libgpg-error's locks.
+//!
+//! These are useful when a lock has to be shared with C code expecting a
+//! `gpgrt_lock_t`. Otherwise, prefer [`std::sync::Mutex`].
+//!
+//! This module is only available on targets for which the layout of
+//! `gpgrt_lock_t` is known.
+use std::{
+    cell::UnsafeCell,
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+use crate::{Error, Result};
+
+#[inline]
+fn check(code: ffi::gpg_err_code_t) -> Result<()> {
+    match code {
+        0 => Ok(()),
+        code => Err(Error::new(code)),
+    }
+}
+
+/// A mutual exclusion lock protecting data of type `T`, backed by a
+/// `gpgrt_lock_t`.
+///
+/// Unlike [`std::sync::Mutex`], the lock is not poisoned if a thread panics
+/// while holding it, and failures of the underlying lock are returned as
+/// errors.
+pub struct GpgrtMutex<T: ?Sized> {
+    lock: Box<UnsafeCell<ffi::gpgrt_lock_t>>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for GpgrtMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for GpgrtMutex<T> {}
+
+impl<T> GpgrtMutex<T> {
+    /// Creates a new, unlocked mutex.
+    pub fn new(value: T) -> Result<Self> {
+        let lock = Box::new(UnsafeCell::new(ffi::GPGRT_LOCK_INITIALIZER));
+        unsafe {
+            check(ffi::gpgrt_lock_init(lock.get()))?;
+        }
+        Ok(Self {
+            lock,
+            data: UnsafeCell::new(value),
+        })
+    }
+
+    /// Destroys the mutex and returns the protected data.
+    pub fn into_inner(self) -> Result<T> {
+        let this = mem::ManuallyDrop::new(self);
+        let result = unsafe { check(ffi::gpgrt_lock_destroy(this.as_raw())) };
+        // Neither field is used again, so moving them out is sound.
+        let (lock, data) = unsafe { (ptr::read(&this.lock), ptr::read(&this.data)) };
+        drop(lock);
+        result.map(|_| data.into_inner())
+    }
+}
+
+impl<T: ?Sized> GpgrtMutex<T> {
+    /// Returns a pointer to the underlying lock, e.g. to pass it to C code.
+    ///
+    /// The pointer stays valid until the mutex is dropped.
+    #[inline]
+    pub fn as_raw(&self) -> *mut ffi::gpgrt_lock_t {
+        self.lock.get()
+    }
+
+    /// Acquires the lock, blocking the current thread until it is available.
+    pub fn lock(&self) -> Result<GpgrtMutexGuard<'_, T>> {
+        unsafe {
+            check(ffi::gpgrt_lock_lock(self.as_raw()))?;
+        }
+        Ok(GpgrtMutexGuard::new(self))
+    }
+
+    /// Attempts to acquire the lock without blocking.
+    ///
+    /// Returns `Ok(None)` if the lock is held elsewhere.
+    pub fn try_lock(&self) -> Result<Option<GpgrtMutexGuard<'_, T>>> {
+        match unsafe { ffi::gpgrt_lock_trylock(self.as_raw()) } {
+            ffi::GPG_ERR_EBUSY => Ok(None),
+            code => check(code).map(|_| Some(GpgrtMutexGuard::new(self))),
+        }
+    }
+
+    /// Returns a mutable reference to the protected data without locking.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: ?Sized> Drop for GpgrtMutex<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_lock_destroy(self.as_raw());
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for GpgrtMutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("GpgrtMutex");
+        match self.try_lock() {
+            Ok(Some(guard)) => d.field("data", &&*guard),
+            _ => d.field("data", &format_args!("<locked>")),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+/// An RAII guard releasing a [`GpgrtMutex`] when dropped.
+///
+/// Use [`unlock`](Self::unlock) to observe errors from releasing the lock.
+#[must_use = "if unused the GpgrtMutex will immediately unlock"]
+pub struct GpgrtMutexGuard<'a, T: ?Sized> {
+    mutex: &'a GpgrtMutex<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for GpgrtMutexGuard<'_, T> {}
+
+impl<'a, T: ?Sized> GpgrtMutexGuard<'a, T> {
+    #[inline]
+    fn new(mutex: &'a GpgrtMutex<T>) -> Self {
+        Self {
+            mutex,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Releases the lock.
+    pub fn unlock(self) -> Result<()> {
+        let raw = self.mutex.as_raw();
+        mem::forget(self);
+        unsafe { check(ffi::gpgrt_lock_unlock(raw)) }
+    }
+}
+
+impl<T: ?Sized> Deref for GpgrtMutexGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for GpgrtMutexGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for GpgrtMutexGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gpgrt_lock_unlock(self.mutex.as_raw());
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for GpgrtMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use super::*;
+
+    #[test]
+    fn test_mutex() {
+        let mutex = Arc::new(GpgrtMutex::new(0u32).unwrap());
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let mutex = Arc::clone(&mutex);
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        *mutex.lock().unwrap() += 1;
+                    }
+                })
+            })
+            .collect();
+        for t in threads {
+            t.join().unwrap();
+        }
+
+        let guard = mutex.lock().unwrap();
+        assert_eq!(*guard, 4000);
+        assert!(mutex.try_lock().unwrap().is_none());
+        guard.unlock().unwrap();
+        assert!(mutex.try_lock().unwrap().is_some());
+        let mutex = Arc::try_unwrap(mutex).ok().unwrap();
+        assert_eq!(mutex.into_inner().unwrap(), 4000);
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::ffi::CString;
+#[cfg(gpg_error_1_41)]
+use alloc::{borrow::ToOwned, format, string::String};
+#[cfg(gpg_error_1_41)]
+use core::{cmp::Ordering, fmt, str::FromStr};
+use core::{ffi::CStr, ptr};
+
+#[cfg(gpg_error_1_41)]
+use crate::{Error, Result};
+
+/// Returns the version of the libgpg-error library in use.
+#[inline]
+pub fn version() -> &'static str {
+    unsafe {
+        CStr::from_ptr(ffi::gpg_error_check_version(ptr::null()))
+            .to_str()
+            .expect("libgpg-error version is not valid utf-8")
+    }
+}
+
+/// Checks that the version of the libgpg-error library in use is at least
+/// `required`.
+///
+/// Returns the library's version if the check succeeds.
+pub fn check_version(required: &str) -> Option<&'static str> {
+    let required = CString::new(required).ok()?;
+    unsafe {
+        ffi::gpg_error_check_version(required.as_ptr())
+            .as_ref()
+            .and_then(|v| CStr::from_ptr(v).to_str().ok())
+    }
+}
+
+/// A parsed `major.minor[.micro][patchlevel]` version string, such as
+/// `1.47` or `2.4.3-beta`.
+///
+/// Versions are ordered using `gpgrt_cmp_version`, with a missing micro
+/// number treated as zero. This requires libgpg-error 1.41 or later.
+#[cfg(gpg_error_1_41)]
+#[derive(Clone)]
+pub struct Version {
+    raw: String,
+    parts: [u32; 3],
+    key: CString,
+}
+
+#[cfg(gpg_error_1_41)]
+impl Version {
+    /// Returns the version of the libgpg-error library in use.
+    #[inline]
+    pub fn current() -> Self {
+        version().parse().expect("invalid libgpg-error version")
+    }
+
+    /// Returns the version string as it was parsed.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the major version number.
+    #[inline]
+    pub fn major(&self) -> u32 {
+        self.parts[0]
+    }
+
+    /// Returns the minor version number.
+    #[inline]
+    pub fn minor(&self) -> u32 {
+        self.parts[1]
+    }
+
+    /// Returns the micro version number, or zero if there is none.
+    #[inline]
+    pub fn micro(&self) -> u32 {
+        self.parts[2]
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl FromStr for Version {
+    type Err = Error;
+
+    /// Parses a version string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::INV_VALUE` if `s` does not start with a
+    /// `major.minor` version number.
+    fn from_str(s: &str) -> Result<Self> {
+        fn number(s: &str) -> Result<(u32, &str)> {
+            let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+            let n = s[..digits].parse().map_err(|_| Error::INV_VALUE)?;
+            Ok((n, &s[digits..]))
+        }
+
+        let (major, rest) = number(s)?;
+        let (minor, mut rest) = number(rest.strip_prefix('.').ok_or(Error::INV_VALUE)?)?;
+        let mut micro = 0;
+        if let Some(r) = rest
+            .strip_prefix('.')
+            .filter(|r| r.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            (micro, rest) = number(r)?;
+        }
+        // gpgrt_cmp_version requires all three numbers at level 3.
+        let key =
+            CString::new(format!("{major}.{minor}.{micro}{rest}")).map_err(|_| Error::EINVAL)?;
+        Ok(Self {
+            raw: s.to_owned(),
+            parts: [major, minor, micro],
+            key,
+        })
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let r = unsafe { ffi::gpgrt_cmp_version(self.key.as_ptr(), other.key.as_ptr(), 3) };
+        r.cmp(&0)
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl PartialOrd for Version {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl PartialEq for Version {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl Eq for Version {}
+
+#[cfg(gpg_error_1_41)]
+impl fmt::Debug for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Version").field(&self.raw).finish()
+    }
+}
+
+#[cfg(gpg_error_1_41)]
+impl fmt::Display for Version {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version() {
+        assert_eq!(check_version("1.0"), Some(version()));
+        assert_eq!(check_version("99.0"), None);
+        assert_eq!(check_version("1\0"), None);
+        #[cfg(gpg_error_1_41)]
+        assert!(Version::current() >= "1.12".parse().unwrap());
+    }
+
+    #[cfg(gpg_error_1_41)]
+    #[test]
+    fn test_ordering() {
+        let v = |s: &str| s.parse::<Version>().unwrap();
+        assert_eq!(v("1.47"), v("1.47.0"));
+        assert_eq!(v("1.47").as_str(), "1.47");
+        assert!(v("1.47") > v("1.46.9"));
+        assert!(v("1.10") > v("1.9"));
+        assert!(v("2.4.3-beta") > v("2.4.3"));
+        assert!(v("2.4.3-a") < v("2.4.3-b"));
+        assert_eq!((v("2.4.3").major(), v("2.4.3").micro()), (2, 3));
+        assert_eq!("1".parse::<Version>().unwrap_err(), Error::INV_VALUE);
+        assert_eq!("x.1".parse::<Version>().unwrap_err(), Error::INV_VALUE);
+    }
+}
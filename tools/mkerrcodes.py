@@ -1,47 +1,118 @@
 #!/usr/bin/env python3
 # Script to generate constant wrappers for error codes
 # from upstream sources.
+import re
 from pathlib import Path
 
 root = Path(__file__).resolve().parent.parent
 
 
-def read_codes(path, list):
+# Comments such as "# 282 to 299 are reserved for future assuan codes."
+RESERVED = re.compile(r'^# (\d+) to (\d+) are (?:reserved|mapped)')
+
+
+def read_codes(path, list, unknown=None, reserved=None):
     with open(root / path, encoding='utf-8') as f:
         for l in f:
-            parts = l.split()
+            m = RESERVED.match(l)
+            if m and reserved is not None:
+                reserved.append((int(m[1]), int(m[2])))
+            parts = l.split(None, 2)
             if len(parts) < 2:
                 continue
             try:
                 int(parts[0])
             except ValueError:
+                if l.startswith('\t') and unknown is not None:
+                    unknown.append(l.split(None, 1)[1].strip())
                 continue
-            list.append((parts[1], parts[0]))
+            list.append((parts[1], parts[0], parts[2].strip() if len(parts) > 2 else None))
+
+
+def c_str(s):
+    return 'c"' + s.replace('\\', '\\\\').replace('"', '\\"') + '"'
 
 
 sources = []
 codes = []
 errnos = []
+unknown_source = []
+unknown_code = []
+reserved = []
 
-for (f, l) in [('err-sources.h.in', sources), ('err-codes.h.in', codes), ('errnos.in', errnos)]:
-    read_codes(Path('vendor') / f, l)
+for (f, l, u, r) in [('err-sources.h.in', sources, unknown_source, None),
+                     ('err-codes.h.in', codes, unknown_code, reserved),
+                     ('errnos.in', errnos, None, None)]:
+    read_codes(Path('vendor') / f, l, u, r)
+
+# Ranges of codes sharing a prefix, including codes reserved for them
+categories = [('Assuan', r'GPG_ERR_ASS_'), ('Dns', r'GPG_ERR_DNS_'), ('Ldap', r'GPG_ERR_LDAP_'),
+              ('User', r'GPG_ERR_USER_\d+$'), ('Sql', r'GPG_ERR_SQL_')]
+ranges = []
+for (category, pattern) in categories:
+    vals = [int(val) for (name, val, _) in codes if re.match(pattern, name)]
+    lo, hi = min(vals), max(vals)
+    for (a, b) in reserved:
+        if lo <= a <= hi + 1 or a <= lo <= b:
+            lo, hi = min(lo, a), max(hi, b)
+    ranges.append((category, lo, hi))
 
 with open(root / 'libgpg-error-sys/src/consts.rs', 'w', encoding='utf-8', newline='\n') as out:
-    for (name, val) in sources:
+    for (name, val, _) in sources:
         out.write(f"pub const {name}: gpg_err_source_t = {val};\n")
-    for (name, val) in codes:
+    for (name, val, _) in codes:
         out.write(f"pub const {name}: gpg_err_code_t = {val};\n")
-    for (name, val) in errnos:
+    for (name, val, _) in errnos:
         out.write(
             f"pub const GPG_ERR_{name}: gpg_err_code_t = GPG_ERR_SYSTEM_ERROR | {val};\n")
+with open(root / 'libgpg-error-sys/src/descriptions.rs', 'w', encoding='utf-8', newline='\n') as out:
+    out.write('static SOURCE_DESCRIPTIONS: &[(gpg_err_source_t, &CStr)] = &[\n')
+    for (_, val, desc) in sources:
+        out.write(f"    ({val}, {c_str(desc)}),\n")
+    out.write('];\n')
+    out.write(f"static UNKNOWN_SOURCE: &CStr = {c_str(unknown_source[0])};\n")
+    out.write('static CODE_DESCRIPTIONS: &[(gpg_err_code_t, &CStr)] = &[\n')
+    for (_, val, desc) in codes:
+        out.write(f"    ({val}, {c_str(desc)}),\n")
+    out.write('];\n')
+    out.write(f"static UNKNOWN_CODE: &CStr = {c_str(unknown_code[0])};\n")
+with open(root / 'libgpg-error-sys/src/errnos.rs', 'w', encoding='utf-8', newline='\n') as out:
+    out.write('/// Zero for the errno names the target does not define; shadowed by the\n')
+    out.write('/// constants of the `libc` crate where it does.\n')
+    out.write('#[allow(dead_code)]\nmod missing {\n    use core::ffi::c_int;\n\n')
+    for (name, _, _) in errnos:
+        out.write(f"    pub const {name}: c_int = 0;\n")
+    out.write('}\n\n')
+    out.write('use self::missing::*;\n\n')
+    out.write(f"static ERRNOS: [c_int; {len(errnos)}] = {{\n    use libc::*;\n\n    [\n")
+    for (name, _, _) in errnos:
+        out.write(f"        {name},\n")
+    out.write('    ]\n};\n')
 with open(root / 'src/consts.rs', 'w', encoding='utf-8', newline='\n') as out:
     out.write('impl Error{\n')
-    for (name, _) in sources:
+    for (name, _, _) in sources:
         out.write(
-            f"pub const {name.removeprefix('GPG_ERR_')}: ErrorSource = ffi::{name};\n")
-    for (name, _) in codes:
+            f"pub const {name.removeprefix('GPG_ERR_')}: ErrorSource = ErrorSource::{name.removeprefix('GPG_ERR_SOURCE_')};\n")
+    for (name, _, _) in codes:
         out.write(
             f"pub const {name.removeprefix('GPG_ERR_')}: Self = Self(ffi::{name});\n")
-    for (name, _) in errnos:
+    for (name, _, _) in errnos:
         out.write(f"pub const {name}: Self = Self(ffi::GPG_ERR_{name});\n")
     out.write('}\n')
+with open(root / 'src/sources.rs', 'w', encoding='utf-8', newline='\n') as out:
+    out.write('error_sources! {\n')
+    for (name, _, _) in sources:
+        out.write(f"    {name.removeprefix('GPG_ERR_SOURCE_')} = ffi::{name},\n")
+    out.write('}\n')
+with open(root / 'src/codes.rs', 'w', encoding='utf-8', newline='\n') as out:
+    out.write('error_codes! {\n')
+    for (name, _, _) in codes:
+        out.write(f"    {name.removeprefix('GPG_ERR_')} = ffi::{name},\n")
+    for (name, _, _) in errnos:
+        out.write(f"    {name} = ffi::GPG_ERR_{name},\n")
+    out.write('}\n')
+with open(root / 'src/ranges.rs', 'w', encoding='utf-8', newline='\n') as out:
+    out.write('error_ranges! {\n')
+    for (category, lo, hi) in ranges:
+        out.write(f"    {category} = {lo}..={hi},\n")
+    out.write('}\n')